@@ -0,0 +1,184 @@
+use crate::constants::{AHP_MAX_ITERATIONS, AHP_RANDOM_INDEX};
+
+/// Parses a judgement of the Saaty scale, written as `3`, `1/3` or `0.333`.
+pub fn parse_judgement(value: &str) -> Result<f32, &'static str> {
+    let judgement = match value.split_once('/') {
+        Some((numerator, denominator)) => {
+            let numerator = numerator.trim().parse::<f32>();
+            let denominator = denominator.trim().parse::<f32>();
+
+            match (numerator, denominator) {
+                (Ok(numerator), Ok(denominator)) if denominator != 0.0 => numerator / denominator,
+                _ => return Err("Judgement is invalid."),
+            }
+        }
+        None => value
+            .trim()
+            .parse::<f32>()
+            .map_err(|_| "Judgement is invalid.")?,
+    };
+
+    if !(1.0 / 9.0 - 1e-3..=9.0).contains(&judgement) {
+        return Err("Judgement is out of the Saaty scale.");
+    }
+
+    Ok(judgement)
+}
+
+pub struct AhpResult {
+    /// Normalized principal eigenvector of the comparison matrix.
+    pub priorities: Vec<f32>,
+    pub lambda_max: f32,
+    pub consistency_index: f32,
+    /// `None` when the random index is zero (matrices up to 2×2 are always
+    /// consistent).
+    pub consistency_ratio: Option<f32>,
+}
+
+/// Derives priorities from a reciprocal pairwise comparison matrix by power
+/// iteration and checks consistency of the judgements.
+pub fn analyze(m: &[Vec<f32>]) -> Result<AhpResult, &'static str> {
+    let n = m.len();
+
+    if n == 0 || m.iter().any(|row| row.len() != n) {
+        return Err("Comparison matrix must be square.");
+    }
+
+    if m.iter().flatten().any(|&value| value <= 0.0) {
+        return Err("Judgements must be positive.");
+    }
+
+    if (0..n).any(|i| (0..n).any(|j| (m[i][j] * m[j][i] - 1.0).abs() > 1e-2)) {
+        return Err("Comparison matrix must be reciprocal.");
+    }
+
+    let multiply = |w: &[f32]| -> Vec<f32> {
+        m.iter()
+            .map(|row| row.iter().zip(w.iter()).map(|(a, w)| a * w).sum())
+            .collect()
+    };
+
+    let mut priorities = vec![1.0 / n as f32; n];
+    for _ in 0..AHP_MAX_ITERATIONS {
+        let product = multiply(&priorities);
+        let sum = product.iter().sum::<f32>();
+        let next: Vec<f32> = product.iter().map(|value| value / sum).collect();
+
+        let change = next
+            .iter()
+            .zip(priorities.iter())
+            .map(|(x, y)| (x - y).abs())
+            .fold(0.0, f32::max);
+        priorities = next;
+
+        if change < 1e-7 {
+            break;
+        }
+    }
+
+    let lambda_max = multiply(&priorities)
+        .iter()
+        .zip(priorities.iter())
+        .map(|(product, w)| product / w)
+        .sum::<f32>()
+        / n as f32;
+
+    let consistency_index = if n > 1 {
+        ((lambda_max - n as f32) / (n as f32 - 1.0)).max(0.0)
+    } else {
+        0.0
+    };
+
+    let random_index = AHP_RANDOM_INDEX
+        .get(n - 1)
+        .copied()
+        .unwrap_or(AHP_RANDOM_INDEX[AHP_RANDOM_INDEX.len() - 1]);
+
+    Ok(AhpResult {
+        priorities,
+        lambda_max,
+        consistency_index,
+        consistency_ratio: (random_index > 0.0).then_some(consistency_index / random_index),
+    })
+}
+
+/// Weights rounded to `decimals` digits, the last one absorbs the rounding
+/// error so that they still sum up to 1.
+pub fn round_weights(weights: &[f32], decimals: i32) -> Vec<f32> {
+    let scale = 10f32.powi(decimals);
+    let mut rounded: Vec<f32> = weights
+        .iter()
+        .map(|weight| (weight * scale).round() / scale)
+        .collect();
+
+    if let Some((last, others)) = rounded.split_last_mut() {
+        let others_sum = others
+            .iter()
+            .map(|weight| (weight * scale).round())
+            .sum::<f32>();
+        *last = (scale - others_sum) / scale;
+    }
+
+    rounded
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ahp::comparison::{analyze, parse_judgement, round_weights};
+
+    #[test]
+    fn test_parse_judgement() {
+        assert_eq!(parse_judgement("3"), Ok(3.0));
+        assert_eq!(parse_judgement(" 1/4 "), Ok(0.25));
+        assert!(parse_judgement("1/0").is_err());
+        assert!(parse_judgement("10").is_err(), "Out of the Saaty scale.");
+        assert!(parse_judgement("a").is_err());
+    }
+
+    #[test]
+    fn test_analyze_consistent() {
+        // Built from weights 0.6, 0.3, 0.1, so it is perfectly consistent.
+        let m = vec![
+            vec![1.0, 2.0, 6.0],
+            vec![0.5, 1.0, 3.0],
+            vec![1.0 / 6.0, 1.0 / 3.0, 1.0],
+        ];
+
+        let result = analyze(&m).unwrap();
+
+        for (priority, expected) in result.priorities.iter().zip([0.6, 0.3, 0.1]) {
+            assert!((priority - expected).abs() < 1e-5);
+        }
+        assert!((result.lambda_max - 3.0).abs() < 1e-4);
+        assert!(result.consistency_ratio.unwrap() < 1e-4);
+    }
+
+    #[test]
+    fn test_analyze_inconsistent() {
+        let m = vec![
+            vec![1.0, 9.0, 1.0 / 9.0],
+            vec![1.0 / 9.0, 1.0, 9.0],
+            vec![9.0, 1.0 / 9.0, 1.0],
+        ];
+
+        let result = analyze(&m).unwrap();
+
+        assert!(
+            result.consistency_ratio.unwrap() > 0.1,
+            "Cyclic judgements are inconsistent."
+        );
+    }
+
+    #[test]
+    fn test_analyze_not_reciprocal() {
+        assert!(analyze(&[vec![1.0, 3.0], vec![3.0, 1.0]]).is_err());
+    }
+
+    #[test]
+    fn test_round_weights() {
+        let weights = round_weights(&[1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0], 4);
+
+        assert_eq!(weights[0], 0.3333);
+        assert!((weights.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,179 @@
+use iced::{
+    widget::{button, column, row, Text},
+    Element,
+};
+
+use crate::{
+    constants::AHP_CONSISTENCY_THRESHOLD,
+    table::{
+        cell::CellMessage,
+        table::{InputTable, InputTableMessage},
+    },
+    value_component::{ValueInput, ValueInputMessage},
+};
+
+use super::comparison::{analyze, parse_judgement, AhpResult};
+
+/// Pairwise comparisons of states (or attributes) in the Saaty scale.
+pub struct AhpEditor {
+    size_input: ValueInput,
+    comparison_table: InputTable,
+    pub result: Option<AhpResult>,
+    pub error_text: String,
+}
+
+#[derive(Clone, Debug)]
+pub enum AhpEditorMessage {
+    SizeMessage(ValueInputMessage),
+    GenerateButtonPressed,
+    ComparisonTable(InputTableMessage),
+    EvaluateButtonPressed,
+    ApplyWeightsButtonPressed,
+}
+
+impl AhpEditor {
+    pub fn new() -> Self {
+        AhpEditor {
+            size_input: ValueInput::new("Кількість елементів".to_string()),
+            comparison_table: InputTable::new(0, 0, false),
+            result: None,
+            error_text: String::new(),
+        }
+    }
+
+    pub fn update(&mut self, message: AhpEditorMessage) {
+        match message {
+            AhpEditorMessage::SizeMessage(ValueInputMessage::ValueChanged(value)) => {
+                self.size_input.value = value
+            }
+            AhpEditorMessage::GenerateButtonPressed => self.generate_comparison_table(),
+            AhpEditorMessage::ComparisonTable(InputTableMessage::CellUpdate(
+                CellMessage::Update(row, col, ValueInputMessage::ValueChanged(value)),
+            )) => self.update_judgement(row, col, value),
+            AhpEditorMessage::ComparisonTable(InputTableMessage::ProbabilityCellUpdate(_)) => {}
+            AhpEditorMessage::EvaluateButtonPressed => self.evaluate(),
+            AhpEditorMessage::ApplyWeightsButtonPressed => {}
+        }
+    }
+
+    fn generate_comparison_table(&mut self) {
+        match self.size_input.value.parse::<usize>() {
+            Ok(size) if size > 0 => {
+                self.comparison_table = InputTable::new(size, size, false).with_row_label("y");
+                (0..size).for_each(|index| {
+                    self.comparison_table
+                        .update_cell(index, index, "1".to_string())
+                });
+                self.result = None;
+                self.error_text = String::new();
+            }
+            _ => {
+                self.error_text = "Кількість елементів має бути додатнім цілим числом.".to_string()
+            }
+        }
+    }
+
+    /// Fills the symmetric cell with the reciprocal judgement.
+    fn update_judgement(&mut self, row: usize, col: usize, value: String) {
+        if row != col {
+            if let Ok(judgement) = parse_judgement(&value) {
+                let reciprocal = match value.split_once('/') {
+                    Some((numerator, denominator)) if numerator.trim() == "1" => {
+                        denominator.trim().to_string()
+                    }
+                    Some((numerator, denominator)) => {
+                        format!("{}/{}", denominator.trim(), numerator.trim())
+                    }
+                    None if judgement >= 1.0 => format!("1/{}", value.trim()),
+                    None => (1.0 / judgement).to_string(),
+                };
+
+                self.comparison_table.update_cell(col, row, reciprocal);
+            }
+        }
+
+        self.comparison_table.update_cell(row, col, value);
+        self.result = None;
+    }
+
+    fn evaluate(&mut self) {
+        let result = self
+            .comparison_table
+            .get_data()
+            .iter()
+            .map(|row| row.iter().map(|value| parse_judgement(value)).collect())
+            .collect::<Result<Vec<Vec<f32>>, _>>()
+            .and_then(|m| analyze(&m));
+
+        match result {
+            Ok(result) => {
+                self.result = Some(result);
+                self.error_text = String::new();
+            }
+            Err(_) => {
+                self.result = None;
+                self.error_text =
+                    "Перевірте матрицю парних порівнянь: значення мають бути за шкалою Сааті (1/9 … 9) і взаємно оберненими."
+                        .to_string();
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, AhpEditorMessage> {
+        let mut content = column![
+            Text::new("Метод аналізу ієрархій").size(24),
+            row![
+                Text::new("Кількість елементів: "),
+                self.size_input.view().map(AhpEditorMessage::SizeMessage),
+                button("Генерувати").on_press(AhpEditorMessage::GenerateButtonPressed),
+            ]
+            .align_items(iced::Alignment::Center),
+        ]
+        .spacing(10);
+
+        if self.comparison_table.is_non_empty() {
+            content = content
+                .push(
+                    self.comparison_table
+                        .view()
+                        .map(AhpEditorMessage::ComparisonTable),
+                )
+                .push(button("Обрахувати").on_press(AhpEditorMessage::EvaluateButtonPressed));
+        }
+
+        if let Some(result) = &self.result {
+            content = content
+                .push(Text::new(format!(
+                    "Пріоритети: {}",
+                    result
+                        .priorities
+                        .iter()
+                        .enumerate()
+                        .map(|(index, priority)| format!("y{} = {:.4}", index + 1, priority))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )))
+                .push(Text::new(format!("λmax = {:.4}", result.lambda_max)))
+                .push(Text::new(format!("CI = {:.4}", result.consistency_index)));
+
+            content = content.push(Text::new(match result.consistency_ratio {
+                Some(ratio) if ratio > AHP_CONSISTENCY_THRESHOLD => format!(
+                    "CR = {:.4} > {} — судження неузгоджені, перегляньте порівняння",
+                    ratio, AHP_CONSISTENCY_THRESHOLD
+                ),
+                Some(ratio) => format!("CR = {:.4}, узгодженість прийнятна", ratio),
+                None => "CR не визначається для матриць до 2×2".to_string(),
+            }));
+
+            content = content.push(
+                button("Перенести у рядок p").on_press(AhpEditorMessage::ApplyWeightsButtonPressed),
+            );
+        }
+
+        if !self.error_text.is_empty() {
+            content = content.push(Text::new(self.error_text.clone()));
+        }
+
+        content.into()
+    }
+}
@@ -0,0 +1,249 @@
+use std::ops::RangeInclusive;
+
+use iced::{
+    alignment, mouse,
+    widget::{
+        canvas::{self, Frame, Geometry, Path, Stroke},
+        text::Shaping,
+        Canvas,
+    },
+    Color, Element, Length, Point, Rectangle, Renderer, Theme, Vector,
+};
+
+use crate::constants::{
+    CHART_HEIGHT, CHART_LEGEND_WIDTH, CHART_MARGIN, CHART_WIDTH, SERIES_COLORS,
+};
+
+use super::utils::alternative_label;
+
+pub struct Series {
+    pub label: String,
+    pub points: Vec<(f32, f32)>,
+    pub color: Color,
+    pub width: f32,
+    /// Points are drawn as separate markers instead of a line.
+    pub markers: bool,
+}
+
+impl Series {
+    /// Series of the matrix row `index`, coloured from the palette by the
+    /// original number of the alternative.
+    pub fn alternative(index: usize, alternatives: &[usize], points: Vec<(f32, f32)>) -> Self {
+        Series {
+            label: alternative_label(index, alternatives),
+            points,
+            color: SERIES_COLORS[alternatives[index] % SERIES_COLORS.len()],
+            width: 1.5,
+            markers: false,
+        }
+    }
+}
+
+pub struct Chart {
+    series: Vec<Series>,
+    vertical_markers: Vec<f32>,
+    highlighted_x: Option<f32>,
+    x_range: RangeInclusive<f32>,
+    y_range: RangeInclusive<f32>,
+    x_label: String,
+}
+
+impl Chart {
+    pub fn new(series: Vec<Series>, x_label: &str) -> Self {
+        let points = || series.iter().flat_map(|series| series.points.iter());
+
+        let x_range = get_range(points().map(|point| point.0));
+        let y_range = get_range(points().map(|point| point.1));
+
+        Chart {
+            series,
+            vertical_markers: Vec::new(),
+            highlighted_x: None,
+            x_range,
+            y_range,
+            x_label: x_label.to_string(),
+        }
+    }
+
+    pub fn vertical_markers(mut self, vertical_markers: Vec<f32>) -> Self {
+        self.vertical_markers = vertical_markers;
+        self
+    }
+
+    pub fn highlighted_x(mut self, x: f32) -> Self {
+        self.highlighted_x = Some(x);
+        self
+    }
+
+    pub fn view<'a, Message: 'a>(self) -> Element<'a, Message> {
+        Canvas::new(self)
+            .width(Length::Fixed(CHART_WIDTH))
+            .height(Length::Fixed(CHART_HEIGHT))
+            .into()
+    }
+
+    fn to_screen(&self, bounds: &Rectangle, (x, y): (f32, f32)) -> Point {
+        let plot_width = bounds.width - 2.0 * CHART_MARGIN - CHART_LEGEND_WIDTH;
+        let plot_height = bounds.height - 2.0 * CHART_MARGIN;
+
+        let x_span = self.x_range.end() - self.x_range.start();
+        let y_span = self.y_range.end() - self.y_range.start();
+
+        Point::new(
+            CHART_MARGIN + (x - self.x_range.start()) / x_span * plot_width,
+            CHART_MARGIN + plot_height - (y - self.y_range.start()) / y_span * plot_height,
+        )
+    }
+}
+
+impl<Message> canvas::Program<Message> for Chart {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let text_color = theme.palette().text;
+        let axis_stroke = Stroke::default().with_color(text_color).with_width(1.0);
+
+        let (x_start, x_end) = (*self.x_range.start(), *self.x_range.end());
+        let (y_start, y_end) = (*self.y_range.start(), *self.y_range.end());
+
+        let origin = self.to_screen(&bounds, (x_start, y_start));
+        frame.stroke(
+            &Path::line(origin, self.to_screen(&bounds, (x_end, y_start))),
+            axis_stroke.clone(),
+        );
+        frame.stroke(
+            &Path::line(origin, self.to_screen(&bounds, (x_start, y_end))),
+            axis_stroke,
+        );
+
+        for x in [x_start, x_end] {
+            frame.fill_text(canvas::Text {
+                content: format!("{x:.2}"),
+                position: self.to_screen(&bounds, (x, y_start)) + Vector::new(0.0, 4.0),
+                color: text_color,
+                size: 12.0,
+                horizontal_alignment: alignment::Horizontal::Center,
+                shaping: Shaping::Advanced,
+                ..canvas::Text::default()
+            });
+        }
+
+        for y in [y_start, y_end] {
+            frame.fill_text(canvas::Text {
+                content: format!("{y:.2}"),
+                position: self.to_screen(&bounds, (x_start, y)) - Vector::new(4.0, 0.0),
+                color: text_color,
+                size: 12.0,
+                horizontal_alignment: alignment::Horizontal::Right,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping: Shaping::Advanced,
+                ..canvas::Text::default()
+            });
+        }
+
+        frame.fill_text(canvas::Text {
+            content: self.x_label.clone(),
+            position: self.to_screen(&bounds, (x_end, y_start)) + Vector::new(0.0, 20.0),
+            color: text_color,
+            size: 14.0,
+            horizontal_alignment: alignment::Horizontal::Right,
+            shaping: Shaping::Advanced,
+            ..canvas::Text::default()
+        });
+
+        let marker_color = Color {
+            a: 0.5,
+            ..text_color
+        };
+        for &x in &self.vertical_markers {
+            frame.stroke(
+                &Path::line(
+                    self.to_screen(&bounds, (x, y_start)),
+                    self.to_screen(&bounds, (x, y_end)),
+                ),
+                Stroke::default().with_color(marker_color).with_width(1.0),
+            );
+            frame.fill_text(canvas::Text {
+                content: format!("{x:.3}"),
+                position: self.to_screen(&bounds, (x, y_end)) - Vector::new(0.0, 4.0),
+                color: text_color,
+                size: 12.0,
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Bottom,
+                shaping: Shaping::Advanced,
+                ..canvas::Text::default()
+            });
+        }
+
+        if let Some(x) = self.highlighted_x {
+            frame.stroke(
+                &Path::line(
+                    self.to_screen(&bounds, (x, y_start)),
+                    self.to_screen(&bounds, (x, y_end)),
+                ),
+                Stroke::default().with_color(text_color).with_width(2.0),
+            );
+        }
+
+        for (index, series) in self.series.iter().enumerate() {
+            if series.markers {
+                for &point in &series.points {
+                    frame.fill(
+                        &Path::circle(self.to_screen(&bounds, point), 2.0 * series.width),
+                        series.color,
+                    );
+                }
+            } else if series.points.len() > 1 {
+                let path = Path::new(|builder| {
+                    builder.move_to(self.to_screen(&bounds, series.points[0]));
+                    for &point in &series.points[1..] {
+                        builder.line_to(self.to_screen(&bounds, point));
+                    }
+                });
+
+                frame.stroke(
+                    &path,
+                    Stroke::default()
+                        .with_color(series.color)
+                        .with_width(series.width),
+                );
+            }
+
+            frame.fill_text(canvas::Text {
+                content: series.label.clone(),
+                position: Point::new(
+                    bounds.width - CHART_LEGEND_WIDTH,
+                    CHART_MARGIN + 16.0 * index as f32,
+                ),
+                color: series.color,
+                size: 14.0,
+                shaping: Shaping::Advanced,
+                ..canvas::Text::default()
+            });
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+fn get_range(values: impl Iterator<Item = f32>) -> RangeInclusive<f32> {
+    let (min, max) = values.fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+        (min.min(value), max.max(value))
+    });
+
+    if !min.is_finite() || !max.is_finite() {
+        0.0..=1.0
+    } else if min == max {
+        (min - 1.0)..=(max + 1.0)
+    } else {
+        min..=max
+    }
+}
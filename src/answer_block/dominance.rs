@@ -0,0 +1,116 @@
+use iced::{
+    widget::{column, Column, Text},
+    Element,
+};
+
+use crate::criterion::dominance::{dominance_relations, non_dominated, Dominance};
+
+use super::{
+    profits_losses_radio::Choise,
+    utils::{all_alternatives, alternative_label, format_variants},
+};
+
+/// Dominance between alternatives, used to prune the matrix before criteria.
+pub struct DominanceBlock {
+    /// Direction of the active criteria, `None` when the shown modes disagree.
+    choise: Option<Choise>,
+    relations: Vec<(usize, usize, Dominance)>,
+    kept_rows: Vec<usize>,
+    /// Numbers of all rows, dominance is found on the full matrix.
+    alternatives: Vec<usize>,
+}
+
+impl DominanceBlock {
+    pub fn new() -> Self {
+        DominanceBlock {
+            choise: None,
+            relations: Vec::new(),
+            kept_rows: Vec::new(),
+            alternatives: Vec::new(),
+        }
+    }
+
+    /// Without a direction nothing is dominated, so pruning keeps every row.
+    pub fn update_data(&mut self, a: &[Vec<f32>], choise: Option<Choise>) {
+        self.choise = choise;
+        self.alternatives = all_alternatives(a.len());
+
+        match choise {
+            Some(choise) => {
+                self.relations = dominance_relations(a, choise);
+                self.kept_rows = non_dominated(a, choise);
+            }
+            None => {
+                self.relations = Vec::new();
+                self.kept_rows = all_alternatives(a.len());
+            }
+        }
+    }
+
+    /// Original numbers of the alternatives left after pruning.
+    pub fn kept_rows(&self) -> &[usize] {
+        &self.kept_rows
+    }
+
+    /// Matrix without dominated alternatives.
+    pub fn prune<T: Clone>(&self, a: &[Vec<T>]) -> Vec<Vec<T>> {
+        self.kept_rows.iter().map(|&row| a[row].clone()).collect()
+    }
+
+    pub fn view<T: 'static>(&self, pruned: bool) -> Element<'static, T> {
+        let Some(choise) = self.choise else {
+            return column![
+                Text::new("Домінування альтернатив").size(24),
+                Text::new(
+                    "Мінімакс розглядає втрати, а інші критерії максимізують виграш, \
+                     тому домінування не визначається."
+                ),
+            ]
+            .spacing(10)
+            .into();
+        };
+
+        let relations: Vec<Element<'static, T>> = if self.relations.is_empty() {
+            vec![Text::new("Домінованих альтернатив немає").into()]
+        } else {
+            self.relations
+                .iter()
+                .map(|(dominating, dominated, kind)| {
+                    Text::new(format!(
+                        "{} {} домінує {}",
+                        alternative_label(*dominating, &self.alternatives),
+                        match kind {
+                            Dominance::Strict => "строго",
+                            Dominance::Weak => "слабко",
+                        },
+                        alternative_label(*dominated, &self.alternatives)
+                    ))
+                    .into()
+                })
+                .collect()
+        };
+
+        let mut content = column![
+            Text::new("Домінування альтернатив").size(24),
+            Text::new(match choise {
+                Choise::Profits => "Матриця містить виграші, як у обраних критеріях",
+                Choise::Losses => "Матриця містить втрати, як у критерії мінімакс",
+            }),
+            Column::with_children(relations).spacing(5),
+            Text::new(format!(
+                "Недоміновані альтернативи: {}",
+                format_variants(&self.kept_rows, &self.alternatives)
+            )),
+        ]
+        .spacing(10);
+
+        // Criteria keep the original numbers of alternatives.
+        if pruned && !self.relations.is_empty() {
+            content = content.push(Text::new(
+                "Критерії обраховано на матриці без домінованих альтернатив.",
+            ));
+        }
+
+        content.into()
+    }
+}
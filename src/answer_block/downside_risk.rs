@@ -0,0 +1,199 @@
+use std::fmt::Display;
+
+use iced::{
+    widget::{column, pick_list, row, Text},
+    Element,
+};
+
+use crate::{
+    constants::PARAMETER_INPUT_WIDTH,
+    criterion::{get_indeces, get_min},
+    risk_measures::{lower_partial_moments, probabilities_of_loss, semivariances},
+    value_component::{ValueInput, ValueInputMessage},
+};
+
+use super::utils::{alternative_label, gen_block};
+
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+pub enum DownsideMeasure {
+    SemivarianceBelowMean,
+    SemivarianceBelowTarget,
+    LowerPartialMoment,
+    ProbabilityOfLoss,
+}
+
+impl DownsideMeasure {
+    pub const ALL: [DownsideMeasure; 4] = [
+        DownsideMeasure::SemivarianceBelowMean,
+        DownsideMeasure::SemivarianceBelowTarget,
+        DownsideMeasure::LowerPartialMoment,
+        DownsideMeasure::ProbabilityOfLoss,
+    ];
+}
+
+impl Display for DownsideMeasure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                DownsideMeasure::SemivarianceBelowMean => "Семіваріація нижче середнього",
+                DownsideMeasure::SemivarianceBelowTarget => "Семіваріація нижче цілі",
+                DownsideMeasure::LowerPartialMoment => "Нижній частковий момент",
+                DownsideMeasure::ProbabilityOfLoss => "Ймовірність втрат",
+            }
+        )
+    }
+}
+
+/// Minimization of a downside risk measure, which unlike the dispersion
+/// doesn't penalize outcomes above the mean or the target.
+pub struct DownsideRiskBlock {
+    a: Vec<Vec<f32>>,
+    p: Vec<f32>,
+    measure: DownsideMeasure,
+    target_input: ValueInput,
+    order_input: ValueInput,
+    values: Vec<f32>,
+    minimization_block: Option<(f32, Vec<usize>)>,
+}
+
+#[derive(Clone, Debug)]
+pub enum DownsideRiskBlockMessage {
+    MeasureSelected(DownsideMeasure),
+    TargetMessage(ValueInputMessage),
+    OrderMessage(ValueInputMessage),
+}
+
+impl DownsideRiskBlock {
+    pub fn new(a: Vec<Vec<f32>>, p: Vec<f32>) -> Self {
+        let mut target_input = ValueInput::new("Ціль".to_string());
+        target_input.value = "0".to_string();
+        let mut order_input = ValueInput::new("Порядок".to_string());
+        order_input.value = "1".to_string();
+
+        let mut block = DownsideRiskBlock {
+            a,
+            p,
+            measure: DownsideMeasure::SemivarianceBelowMean,
+            target_input,
+            order_input,
+            values: Vec::new(),
+            minimization_block: None,
+        };
+        block.update_results();
+
+        block
+    }
+
+    pub fn update_data(&mut self, a: Vec<Vec<f32>>, p: Vec<f32>) {
+        self.a = a;
+        self.p = p;
+
+        self.update_results();
+    }
+
+    pub fn update(&mut self, message: DownsideRiskBlockMessage) {
+        match message {
+            DownsideRiskBlockMessage::MeasureSelected(measure) => self.measure = measure,
+            DownsideRiskBlockMessage::TargetMessage(ValueInputMessage::ValueChanged(value)) => {
+                self.target_input.value = value
+            }
+            DownsideRiskBlockMessage::OrderMessage(ValueInputMessage::ValueChanged(value)) => {
+                self.order_input.value = value
+            }
+        }
+
+        self.update_results();
+    }
+
+    /// Invalid target or order hide the result.
+    fn update_results(&mut self) {
+        let target = self.target_input.value.parse::<f32>();
+        let order = self.order_input.value.parse::<f32>();
+
+        let values = match (self.measure, target, order) {
+            (DownsideMeasure::SemivarianceBelowMean, _, _) => {
+                Some(semivariances(&self.a, &self.p, None))
+            }
+            (DownsideMeasure::SemivarianceBelowTarget, Ok(target), _) => {
+                Some(semivariances(&self.a, &self.p, Some(target)))
+            }
+            (DownsideMeasure::LowerPartialMoment, Ok(target), Ok(order)) if order >= 0.0 => {
+                Some(lower_partial_moments(&self.a, &self.p, target, order))
+            }
+            (DownsideMeasure::ProbabilityOfLoss, Ok(target), _) => {
+                Some(probabilities_of_loss(&self.a, &self.p, target))
+            }
+            _ => None,
+        };
+
+        self.minimization_block = values.as_ref().map(|values| {
+            let answer = get_min(values);
+            (answer, get_indeces(values, answer))
+        });
+        self.values = values.unwrap_or_default();
+    }
+
+    pub fn view(&self, alternatives: &[usize]) -> Element<'_, DownsideRiskBlockMessage> {
+        let mut settings = row![pick_list(
+            &DownsideMeasure::ALL[..],
+            Some(self.measure),
+            DownsideRiskBlockMessage::MeasureSelected
+        )]
+        .spacing(10)
+        .align_items(iced::Alignment::Center);
+
+        if self.measure != DownsideMeasure::SemivarianceBelowMean {
+            settings = settings.push(Text::new("ціль t =")).push(
+                column![self
+                    .target_input
+                    .view()
+                    .map(DownsideRiskBlockMessage::TargetMessage)]
+                .width(PARAMETER_INPUT_WIDTH),
+            );
+        }
+
+        if self.measure == DownsideMeasure::LowerPartialMoment {
+            settings = settings.push(Text::new("порядок n =")).push(
+                column![self
+                    .order_input
+                    .view()
+                    .map(DownsideRiskBlockMessage::OrderMessage)]
+                .width(PARAMETER_INPUT_WIDTH),
+            );
+        }
+
+        let mut content =
+            column![Text::new("Мінімізація ризику втрат").height(20), settings].spacing(10);
+
+        match &self.minimization_block {
+            Some(minimization_block) => {
+                content = content
+                    .push(Text::new(
+                        self.values
+                            .iter()
+                            .enumerate()
+                            .map(|(index, value)| {
+                                format!("{} = {:.4}", alternative_label(index, alternatives), value)
+                            })
+                            .collect::<Vec<_>>()
+                            .join("; "),
+                    ))
+                    .push(gen_block(
+                        "Найкраща альтернатива",
+                        minimization_block.0,
+                        &minimization_block.1,
+                        alternatives,
+                    ));
+            }
+            None => {
+                content = content.push(Text::new(
+                    "Ціль має бути числом, а порядок — невідʼємним числом.",
+                ));
+            }
+        }
+
+        content.into()
+    }
+}
@@ -0,0 +1,170 @@
+use iced::{
+    widget::{button, column, row, Text},
+    Element,
+};
+
+use crate::{
+    criterion::experiment::{analyze, ExperimentAnalysis, ExperimentError},
+    table::table::{InputTable, InputTableMessage},
+    utils::parse_data,
+    value_component::{ValueInput, ValueInputMessage},
+};
+
+use super::utils::format_variants;
+
+/// Bayesian updating of the prior `p` with results of an experiment given
+/// as likelihoods `P(signal | state)`.
+pub struct ExperimentBlock {
+    signals_input: ValueInput,
+    pub likelihood_table: InputTable,
+    analysis: Option<ExperimentAnalysis>,
+    error_text: String,
+}
+
+#[derive(Clone, Debug)]
+pub enum ExperimentBlockMessage {
+    SignalsMessage(ValueInputMessage),
+    GenerateButtonPressed,
+    LikelihoodTable(InputTableMessage),
+    AnalyzeButtonPressed,
+}
+
+impl ExperimentBlock {
+    pub fn new() -> Self {
+        ExperimentBlock {
+            signals_input: ValueInput::new("Кількість сигналів".to_string()),
+            likelihood_table: InputTable::new(0, 0, false),
+            analysis: None,
+            error_text: String::new(),
+        }
+    }
+
+    pub fn view(&self, alternatives: &[usize]) -> Element<'_, ExperimentBlockMessage> {
+        let mut content = column![
+            Text::new("Баєсове оновлення ймовірностей").height(20),
+            row![
+                Text::new("Кількість сигналів: "),
+                self.signals_input
+                    .view()
+                    .map(ExperimentBlockMessage::SignalsMessage),
+                button("Генерувати").on_press(ExperimentBlockMessage::GenerateButtonPressed),
+            ]
+            .align_items(iced::Alignment::Center),
+        ]
+        .spacing(10);
+
+        if self.likelihood_table.is_non_empty() {
+            content = content
+                .push(Text::new("P(s | y):"))
+                .push(
+                    self.likelihood_table
+                        .view()
+                        .map(ExperimentBlockMessage::LikelihoodTable),
+                )
+                .push(button("Обрахувати").on_press(ExperimentBlockMessage::AnalyzeButtonPressed));
+        }
+
+        if let Some(analysis) = &self.analysis {
+            for (signal, ((signal_probability, posterior), decision)) in analysis
+                .signal_probabilities
+                .iter()
+                .zip(analysis.posteriors.iter())
+                .zip(analysis.decisions.iter())
+                .enumerate()
+            {
+                let decision_text = match decision {
+                    Some((value, indeces)) => {
+                        format!(
+                            "Z = {:.4}, варіанти: {}",
+                            value,
+                            format_variants(indeces, alternatives)
+                        )
+                    }
+                    None => "сигнал неможливий".to_string(),
+                };
+
+                content = content.push(Text::new(format!(
+                    "s{}: P = {:.4}; апостеріорні ймовірності: {}; {}",
+                    signal + 1,
+                    signal_probability,
+                    posterior
+                        .iter()
+                        .map(|p| format!("{p:.4}"))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    decision_text
+                )));
+            }
+
+            content = content
+                .push(Text::new(format!(
+                    "Очікуване значення з вибірковою інформацією = {:.4}",
+                    analysis.expected_value_with_sample_information
+                )))
+                .push(Text::new(format!("EVSI = {:.4}", analysis.evsi)))
+                .push(Text::new(match analysis.efficiency {
+                    Some(efficiency) => format!("Ефективність = {:.2}%", efficiency * 100.0),
+                    None => "Ефективність не визначена (EVPI = 0)".to_string(),
+                }));
+        }
+
+        if !self.error_text.is_empty() {
+            content = content.push(Text::new(self.error_text.clone()));
+        }
+
+        content.into()
+    }
+
+    pub fn update_signals_input(&mut self, value: String) {
+        self.signals_input.value = value;
+    }
+
+    pub fn generate_likelihood_table(&mut self, states: usize) {
+        match self.signals_input.value.parse::<usize>() {
+            Ok(signals) if signals > 0 => {
+                self.likelihood_table = InputTable::new(signals, states, false).with_row_label("s");
+                self.analysis = None;
+                self.error_text = String::new();
+            }
+            _ => self.error_text = "Кількість сигналів має бути додатнім цілим числом.".to_string(),
+        }
+    }
+
+    pub fn analyze(&mut self, a: &[Vec<f32>], p: &[f32]) {
+        let likelihood_data = self.likelihood_table.get_data();
+        let result = match parse_data(&likelihood_data) {
+            Ok(likelihoods) => analyze(a, p, &likelihoods).map_err(|error| match error {
+                ExperimentError::SizeMismatch => {
+                    "Кількість стовпців матриці правдоподібностей має дорівнювати кількості станів."
+                        .to_string()
+                }
+                ExperimentError::LikelihoodOutOfRange => {
+                    "Правдоподібності мають бути в межах [0; 1].".to_string()
+                }
+                ExperimentError::UnnormalizedState(state) => format!(
+                    "Правдоподібності стану y{} мають в сумі дорівнювати 1.",
+                    state + 1
+                ),
+            }),
+            Err(_) => Err("Матриця правдоподібностей містить некоректні числа.".to_string()),
+        };
+
+        match result {
+            Ok(analysis) => {
+                self.analysis = Some(analysis);
+                self.error_text = String::new();
+            }
+            Err(error_text) => {
+                self.analysis = None;
+                self.error_text = error_text;
+            }
+        }
+    }
+
+    /// Refreshes results after the payoff matrix or prior has changed.
+    pub fn refresh(&mut self, a: &[Vec<f32>], p: &[f32]) {
+        if self.analysis.is_some() {
+            self.analyze(a, p);
+        }
+    }
+}
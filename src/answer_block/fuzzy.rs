@@ -0,0 +1,159 @@
+use iced::{
+    widget::{column, Column, Text},
+    Element,
+};
+
+use crate::fuzzy::{
+    bayes, hurwitz, maximax, modal, savage, wald, Defuzzification, FuzzyDecision, FuzzyNumber,
+};
+
+use super::{
+    profits_losses_radio::Choise,
+    slider_block::{SliderBlock, SliderBlockMessage},
+    utils::{alternative_label, ranking_block},
+};
+
+/// Criteria over fuzzy payoffs. The defuzzification method belongs to the
+/// application, it also gives the crisp matrix for the other criteria.
+pub struct FuzzyBlock {
+    a: Vec<Vec<FuzzyNumber>>,
+    p: Option<Vec<f32>>,
+    /// Direction of the Wald criterion, follows the minimax radio.
+    profits_losses: Choise,
+    /// Original numbers of the rows of `a`, dominated rows may be pruned.
+    alternatives: Vec<usize>,
+    defuzzification: Defuzzification,
+    alpha_slider: SliderBlock<f32>,
+    decisions: Vec<(&'static str, FuzzyDecision)>,
+}
+
+#[derive(Clone, Debug)]
+pub enum FuzzyBlockMessage {
+    Alpha(SliderBlockMessage<f32>),
+}
+
+impl FuzzyBlock {
+    pub fn new(
+        a: Vec<Vec<FuzzyNumber>>,
+        p: Option<Vec<f32>>,
+        profits_losses: Choise,
+        alternatives: Vec<usize>,
+        defuzzification: Defuzzification,
+    ) -> Self {
+        let mut block = FuzzyBlock {
+            a,
+            p,
+            profits_losses,
+            alternatives,
+            defuzzification,
+            alpha_slider: SliderBlock::new(0.5, 0.01, 0.0..=1.0),
+            decisions: Vec::new(),
+        };
+        block.update_decisions();
+
+        block
+    }
+
+    pub fn update_data(
+        &mut self,
+        a: Vec<Vec<FuzzyNumber>>,
+        p: Option<Vec<f32>>,
+        profits_losses: Choise,
+        alternatives: Vec<usize>,
+        defuzzification: Defuzzification,
+    ) {
+        self.a = a;
+        self.p = p;
+        self.profits_losses = profits_losses;
+        self.alternatives = alternatives;
+        self.defuzzification = defuzzification;
+
+        self.update_decisions();
+    }
+
+    pub fn set_defuzzification(&mut self, defuzzification: Defuzzification) {
+        self.defuzzification = defuzzification;
+    }
+
+    pub fn update(&mut self, message: FuzzyBlockMessage) {
+        match message {
+            FuzzyBlockMessage::Alpha(SliderBlockMessage::AlphaChange(value)) => {
+                self.alpha_slider.value = value
+            }
+        }
+
+        self.update_decisions();
+    }
+
+    fn update_decisions(&mut self) {
+        self.decisions = vec![
+            ("Вальда", wald(&self.a, self.profits_losses)),
+            ("Максимакс", maximax(&self.a)),
+            ("Гурвіца", hurwitz(&self.a, self.alpha_slider.value)),
+            ("Севіджа (максимальний жаль)", savage(&self.a)),
+        ];
+
+        if let Some(p) = &self.p {
+            self.decisions.push(("Байєса", bayes(&self.a, p)));
+
+            if let Some(decision) = modal(&self.a, p) {
+                self.decisions.push(("Модальний", decision));
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, FuzzyBlockMessage> {
+        let alternatives = &self.alternatives;
+
+        let decisions = self
+            .decisions
+            .iter()
+            .map(|(title, decision)| {
+                let defuzzified = decision.defuzzified(self.defuzzification);
+
+                column![
+                    Text::new(*title).height(20),
+                    Text::new(
+                        decision
+                            .scores
+                            .iter()
+                            .enumerate()
+                            .map(|(index, score)| {
+                                format!("{} = {}", alternative_label(index, alternatives), score)
+                            })
+                            .collect::<Vec<_>>()
+                            .join("; ")
+                    ),
+                    Text::new(if decision.minimize {
+                        "Ранжування (менше — краще):"
+                    } else {
+                        "Ранжування:"
+                    }),
+                    ranking_block(
+                        &defuzzified,
+                        &decision.ranking(self.defuzzification),
+                        alternatives
+                    ),
+                ]
+                .spacing(5)
+                .into()
+            })
+            .collect();
+
+        let mut content = column![
+            Text::new("α критерію Гурвіца:"),
+            self.alpha_slider.view().map(FuzzyBlockMessage::Alpha),
+            Column::with_children(decisions).spacing(20),
+        ]
+        .spacing(10);
+
+        if self.p.is_some() {
+            content = content.push(Text::new(
+                "Мінімізація дисперсії та максимізація ймовірності не мають нечітких версій: \
+                 їх обраховано лише на дефазифікованій матриці.",
+            ));
+        }
+
+        content.into()
+    }
+}
@@ -0,0 +1,252 @@
+use iced::{
+    widget::{button, column, row, Column, Text},
+    Element,
+};
+
+use crate::{
+    constants::{BROWN_ROBINSON_MAX_ITERATIONS, BROWN_ROBINSON_TABLE_ROWS, SERIES_COLORS},
+    game_theory::{brown_robinson, solve, BrownRobinsonSolution, GameSolution},
+    value_component::{ValueInput, ValueInputMessage},
+};
+
+use super::{
+    chart::{Chart, Series},
+    utils::format_values,
+};
+
+pub struct GameAnswerBlocks {
+    a: Vec<Vec<f32>>,
+    solution: GameSolution,
+    iterations_input: ValueInput,
+    tolerance_input: ValueInput,
+    brown_robinson: Option<BrownRobinsonSolution>,
+    brown_robinson_error_text: String,
+}
+
+#[derive(Clone, Debug)]
+pub enum GameAnswerBlocksMessage {
+    IterationsMessage(ValueInputMessage),
+    ToleranceMessage(ValueInputMessage),
+    BrownRobinsonButtonPressed,
+}
+
+impl GameAnswerBlocks {
+    pub fn new(a: Vec<Vec<f32>>) -> Self {
+        let mut iterations_input = ValueInput::new("Ітерації".to_string());
+        iterations_input.value = "100".to_string();
+        let mut tolerance_input = ValueInput::new("Точність".to_string());
+        tolerance_input.value = "0.01".to_string();
+
+        GameAnswerBlocks {
+            solution: solve(&a),
+            a,
+            iterations_input,
+            tolerance_input,
+            brown_robinson: None,
+            brown_robinson_error_text: String::new(),
+        }
+    }
+
+    /// Re-solves the game for a new matrix, keeping the iteration settings.
+    pub fn update_data(&mut self, a: Vec<Vec<f32>>) {
+        self.solution = solve(&a);
+        self.a = a;
+
+        if self.brown_robinson.is_some() {
+            self.run_brown_robinson();
+        }
+    }
+
+    pub fn update(&mut self, message: GameAnswerBlocksMessage) {
+        match message {
+            GameAnswerBlocksMessage::IterationsMessage(ValueInputMessage::ValueChanged(value)) => {
+                self.iterations_input.value = value
+            }
+            GameAnswerBlocksMessage::ToleranceMessage(ValueInputMessage::ValueChanged(value)) => {
+                self.tolerance_input.value = value
+            }
+            GameAnswerBlocksMessage::BrownRobinsonButtonPressed => self.run_brown_robinson(),
+        }
+    }
+
+    fn run_brown_robinson(&mut self) {
+        let iterations = self.iterations_input.value.parse::<usize>();
+        let tolerance = self.tolerance_input.value.parse::<f32>();
+
+        match (iterations, tolerance) {
+            (Ok(iterations), Ok(tolerance))
+                if (1..=BROWN_ROBINSON_MAX_ITERATIONS).contains(&iterations)
+                    && tolerance >= 0.0 =>
+            {
+                self.brown_robinson = Some(brown_robinson(
+                    &self.a,
+                    iterations,
+                    tolerance,
+                    BROWN_ROBINSON_TABLE_ROWS,
+                ));
+                self.brown_robinson_error_text = String::new();
+            }
+            _ => {
+                self.brown_robinson = None;
+                self.brown_robinson_error_text = format!(
+                    "Кількість ітерацій має бути цілим числом від 1 до {}, а точність — невідʼємним числом.",
+                    BROWN_ROBINSON_MAX_ITERATIONS
+                );
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, GameAnswerBlocksMessage> {
+        let solution = &self.solution;
+
+        let format_strategies = |label: &str, indeces: &Vec<usize>| {
+            indeces
+                .iter()
+                .map(|index| format!("{}{}", label, index + 1))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        column![
+            column![
+                Text::new("Сідлова точка").height(20),
+                Text::new(format!("Нижня ціна гри α = {}", solution.lower_value)),
+                Text::new(format!("Верхня ціна гри β = {}", solution.upper_value)),
+                Text::new(match solution.saddle_point {
+                    Some((row, col)) => format!("Сідлова точка: (x{}, y{})", row + 1, col + 1),
+                    None => "Сідлової точки немає, α < β".to_string(),
+                }),
+            ]
+            .spacing(10),
+            column![
+                Text::new("Домінування").height(20),
+                Text::new(format!(
+                    "Залишені стратегії: {}; {}",
+                    format_strategies("x", &solution.kept_rows),
+                    format_strategies("y", &solution.kept_cols)
+                )),
+            ]
+            .spacing(10),
+            column![
+                Text::new("Змішані стратегії").height(20),
+                Text::new(format!("Ціна гри v = {:.4}", solution.value)),
+                Text::new(format!("P = ({})", format_values(&solution.row_strategy))),
+                Text::new(format!("Q = ({})", format_values(&solution.col_strategy))),
+            ]
+            .spacing(10),
+            self.brown_robinson_view(),
+        ]
+        .spacing(40)
+        .into()
+    }
+
+    fn brown_robinson_view(&self) -> Element<'_, GameAnswerBlocksMessage> {
+        let mut content = column![
+            Text::new("Метод Брауна–Робінсон").height(20),
+            row![
+                Text::new("Ітерації: "),
+                self.iterations_input
+                    .view()
+                    .map(GameAnswerBlocksMessage::IterationsMessage),
+                Text::new("Точність: "),
+                self.tolerance_input
+                    .view()
+                    .map(GameAnswerBlocksMessage::ToleranceMessage),
+                button("Обрахувати").on_press(GameAnswerBlocksMessage::BrownRobinsonButtonPressed),
+            ]
+            .align_items(iced::Alignment::Center),
+        ]
+        .spacing(10);
+
+        if let Some(solution) = &self.brown_robinson {
+            let header = Text::new("k | x | y | виграші x | програші y | v̄ | v̲");
+            let rows = solution
+                .iterations
+                .iter()
+                .enumerate()
+                .map(|(index, iteration)| {
+                    Text::new(format!(
+                        "{} | x{} | y{} | {} | {} | {:.4} | {:.4}",
+                        index + 1,
+                        iteration.row_choice + 1,
+                        iteration.col_choice + 1,
+                        format_values(&iteration.row_gains),
+                        format_values(&iteration.col_losses),
+                        iteration.upper_bound,
+                        iteration.lower_bound
+                    ))
+                    .into()
+                })
+                .collect();
+
+            content = content
+                .push(header)
+                .push(Column::with_children(rows).spacing(5));
+
+            if solution.bounds.len() > solution.iterations.len() {
+                content = content.push(Text::new(format!(
+                    "... показано {} з {} ітерацій",
+                    solution.iterations.len(),
+                    solution.bounds.len()
+                )));
+            }
+
+            content = content
+                .push(brown_robinson_chart(solution))
+                .push(Text::new(if solution.converged {
+                    format!("Досягнуто точність за {} ітерацій", solution.bounds.len())
+                } else {
+                    "Точність не досягнуто, оцінка за останньою ітерацією".to_string()
+                }))
+                .push(Text::new(format!(
+                    "{:.4} ≤ v ≤ {:.4}, v ≈ {:.4}",
+                    solution.lower_bound, solution.upper_bound, solution.value
+                )))
+                .push(Text::new(format!(
+                    "P ≈ ({})",
+                    format_values(&solution.row_strategy)
+                )))
+                .push(Text::new(format!(
+                    "Q ≈ ({})",
+                    format_values(&solution.col_strategy)
+                )));
+        }
+
+        if !self.brown_robinson_error_text.is_empty() {
+            content = content.push(Text::new(self.brown_robinson_error_text.clone()));
+        }
+
+        content.into()
+    }
+}
+
+/// Upper and lower estimates of the game value against the iteration number.
+fn brown_robinson_chart<'a>(
+    solution: &BrownRobinsonSolution,
+) -> Element<'a, GameAnswerBlocksMessage> {
+    let bound_series = |label: &str, color_index: usize, bound: fn(f32, f32) -> f32| {
+        let points = solution
+            .bounds
+            .iter()
+            .enumerate()
+            .map(|(index, &(upper, lower))| ((index + 1) as f32, bound(upper, lower)))
+            .collect();
+
+        Series {
+            label: label.to_string(),
+            points,
+            color: SERIES_COLORS[color_index],
+            width: 1.5,
+            markers: false,
+        }
+    };
+
+    Chart::new(
+        vec![
+            bound_series("v̄", 3, |upper, _| upper),
+            bound_series("v̲", 0, |_, lower| lower),
+        ],
+        "k",
+    )
+    .view()
+}
@@ -0,0 +1,133 @@
+use iced::{
+    widget::{column, Column, Text},
+    Element,
+};
+
+use crate::interval::{bayes, hurwitz, maximax, savage, wald, Interval, IntervalDecision};
+
+use super::{
+    profits_losses_radio::Choise,
+    slider_block::{SliderBlock, SliderBlockMessage},
+    utils::{alternative_label, format_variants},
+};
+
+/// Criteria over interval payoffs with robustness of the chosen alternative.
+pub struct IntervalBlock {
+    a: Vec<Vec<Interval>>,
+    p: Option<Vec<f32>>,
+    /// Direction of the Wald criterion, follows the minimax radio.
+    profits_losses: Choise,
+    /// Original numbers of the rows of `a`, dominated rows may be pruned.
+    alternatives: Vec<usize>,
+    alpha_slider: SliderBlock<f32>,
+    decisions: Vec<(&'static str, IntervalDecision)>,
+}
+
+#[derive(Clone, Debug)]
+pub enum IntervalBlockMessage {
+    Alpha(SliderBlockMessage<f32>),
+}
+
+impl IntervalBlock {
+    pub fn new(
+        a: Vec<Vec<Interval>>,
+        p: Option<Vec<f32>>,
+        profits_losses: Choise,
+        alternatives: Vec<usize>,
+    ) -> Self {
+        let mut block = IntervalBlock {
+            a,
+            p,
+            profits_losses,
+            alternatives,
+            alpha_slider: SliderBlock::new(0.5, 0.01, 0.0..=1.0),
+            decisions: Vec::new(),
+        };
+        block.update_decisions();
+
+        block
+    }
+
+    pub fn update_data(
+        &mut self,
+        a: Vec<Vec<Interval>>,
+        p: Option<Vec<f32>>,
+        profits_losses: Choise,
+        alternatives: Vec<usize>,
+    ) {
+        self.a = a;
+        self.p = p;
+        self.profits_losses = profits_losses;
+        self.alternatives = alternatives;
+
+        self.update_decisions();
+    }
+
+    pub fn update(&mut self, message: IntervalBlockMessage) {
+        match message {
+            IntervalBlockMessage::Alpha(SliderBlockMessage::AlphaChange(value)) => {
+                self.alpha_slider.value = value
+            }
+        }
+
+        self.update_decisions();
+    }
+
+    fn update_decisions(&mut self) {
+        self.decisions = vec![
+            ("Вальда", wald(&self.a, self.profits_losses)),
+            ("Максимакс", maximax(&self.a)),
+            ("Гурвіца", hurwitz(&self.a, self.alpha_slider.value)),
+            ("Севіджа (максимальний жаль)", savage(&self.a)),
+        ];
+
+        if let Some(p) = &self.p {
+            self.decisions.push(("Байєса", bayes(&self.a, p)));
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, IntervalBlockMessage> {
+        let alternatives = &self.alternatives;
+
+        let decisions = self
+            .decisions
+            .iter()
+            .map(|(title, decision)| {
+                column![
+                    Text::new(*title).height(20),
+                    Text::new(
+                        decision
+                            .scores
+                            .iter()
+                            .enumerate()
+                            .map(|(index, score)| {
+                                format!("{} ∈ {}", alternative_label(index, alternatives), score)
+                            })
+                            .collect::<Vec<_>>()
+                            .join("; ")
+                    ),
+                    Text::new(format!(
+                        "Найкраща за серединою інтервалу: {}",
+                        format_variants(&decision.best, alternatives)
+                    )),
+                    Text::new(if decision.robust {
+                        "Вибір стійкий для будь-яких значень з інтервалів"
+                    } else {
+                        "Вибір не стійкий: інтервали оцінок перетинаються"
+                    }),
+                ]
+                .spacing(5)
+                .into()
+            })
+            .collect();
+
+        column![
+            Text::new("Інтервальні виграші").size(24),
+            Text::new("α критерію Гурвіца:"),
+            self.alpha_slider.view().map(IntervalBlockMessage::Alpha),
+            Column::with_children(decisions).spacing(20),
+        ]
+        .spacing(10)
+        .into()
+    }
+}
@@ -1,5 +1,21 @@
+pub mod chart;
+pub mod dominance;
+pub mod downside_risk;
+pub mod experiment;
+pub mod fuzzy;
+pub mod game;
+pub mod interval;
+pub mod monte_carlo;
+pub mod multi_criteria;
+pub mod outranking;
+pub mod outranking_graph;
 pub mod profits_losses_radio;
+pub mod prospect_theory;
 pub mod risk_condition;
+pub mod risk_measures;
+pub mod simplex_chart;
 pub mod slider_block;
+pub mod stochastic_dominance;
 pub mod uncertainty;
+pub mod utility;
 pub mod utils;
@@ -0,0 +1,276 @@
+use iced::{
+    widget::{button, column, pick_list, row, Text},
+    Element,
+};
+
+use crate::{
+    constants::{MONTE_CARLO_CHART_POINTS, MONTE_CARLO_MAX_SAMPLES, PARAMETER_INPUT_WIDTH},
+    monte_carlo::{simulate, CriterionSimulation, Distribution, SimulatedCriterion},
+    value_component::{ValueInput, ValueInputMessage},
+};
+
+use super::{
+    chart::{Chart, Series},
+    profits_losses_radio::Choise,
+    slider_block::{SliderBlock, SliderBlockMessage},
+    utils::matrix_block,
+};
+
+/// Seeded Monte Carlo simulation over matrices with distribution cells.
+pub struct MonteCarloBlock {
+    cells: Vec<Vec<Distribution>>,
+    p: Option<Vec<f32>>,
+    /// Direction of the Wald criterion, follows the minimax radio.
+    profits_losses: Choise,
+    /// Original numbers of the rows of `cells`, dominated rows may be pruned.
+    alternatives: Vec<usize>,
+    samples_input: ValueInput,
+    seed_input: ValueInput,
+    alpha_slider: SliderBlock<f32>,
+    selected_criterion: SimulatedCriterion,
+    simulations: Option<Vec<CriterionSimulation>>,
+    error_text: String,
+}
+
+#[derive(Clone, Debug)]
+pub enum MonteCarloBlockMessage {
+    SamplesMessage(ValueInputMessage),
+    SeedMessage(ValueInputMessage),
+    Alpha(SliderBlockMessage<f32>),
+    CriterionSelected(SimulatedCriterion),
+    SimulateButtonPressed,
+}
+
+impl MonteCarloBlock {
+    pub fn new(
+        cells: Vec<Vec<Distribution>>,
+        p: Option<Vec<f32>>,
+        profits_losses: Choise,
+        alternatives: Vec<usize>,
+    ) -> Self {
+        let mut samples_input = ValueInput::new("Вибірки".to_string());
+        samples_input.value = "1000".to_string();
+        let mut seed_input = ValueInput::new("Зерно".to_string());
+        seed_input.value = "42".to_string();
+
+        MonteCarloBlock {
+            cells,
+            p,
+            profits_losses,
+            alternatives,
+            samples_input,
+            seed_input,
+            alpha_slider: SliderBlock::new(0.5, 0.01, 0.0..=1.0),
+            selected_criterion: SimulatedCriterion::Wald,
+            simulations: None,
+            error_text: String::new(),
+        }
+    }
+
+    /// Replaces the matrix, keeping the settings. A finished simulation is
+    /// dropped when the data changes, it is rerun only by the button.
+    pub fn update_data(
+        &mut self,
+        cells: Vec<Vec<Distribution>>,
+        p: Option<Vec<f32>>,
+        profits_losses: Choise,
+        alternatives: Vec<usize>,
+    ) {
+        let changed = self.cells != cells
+            || self.p != p
+            || self.profits_losses != profits_losses
+            || self.alternatives != alternatives;
+
+        self.cells = cells;
+        self.p = p;
+        self.profits_losses = profits_losses;
+        self.alternatives = alternatives;
+
+        if changed && self.simulations.is_some() {
+            self.simulations = None;
+            self.error_text = "Дані змінено: запустіть моделювання знову.".to_string();
+        }
+    }
+
+    pub fn update(&mut self, message: MonteCarloBlockMessage) {
+        match message {
+            MonteCarloBlockMessage::SamplesMessage(ValueInputMessage::ValueChanged(value)) => {
+                self.samples_input.value = value
+            }
+            MonteCarloBlockMessage::SeedMessage(ValueInputMessage::ValueChanged(value)) => {
+                self.seed_input.value = value
+            }
+            MonteCarloBlockMessage::Alpha(SliderBlockMessage::AlphaChange(value)) => {
+                self.alpha_slider.value = value
+            }
+            MonteCarloBlockMessage::CriterionSelected(criterion) => {
+                self.selected_criterion = criterion
+            }
+            MonteCarloBlockMessage::SimulateButtonPressed => self.run_simulation(),
+        }
+    }
+
+    fn run_simulation(&mut self) {
+        let samples = self.samples_input.value.parse::<usize>();
+        let seed = self.seed_input.value.parse::<u64>();
+
+        match (samples, seed) {
+            (Ok(samples), Ok(seed)) if (1..=MONTE_CARLO_MAX_SAMPLES).contains(&samples) => {
+                self.simulations = Some(simulate(
+                    &self.cells,
+                    self.p.as_deref(),
+                    self.alpha_slider.value,
+                    self.profits_losses,
+                    samples,
+                    seed,
+                ));
+                self.error_text = String::new();
+            }
+            _ => {
+                self.simulations = None;
+                self.error_text = format!(
+                    "Кількість вибірок має бути цілим числом від 1 до {}, а зерно — невідʼємним цілим числом.",
+                    MONTE_CARLO_MAX_SAMPLES
+                );
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, MonteCarloBlockMessage> {
+        let alternatives = &self.alternatives;
+
+        let mut content = column![
+            Text::new("Імітаційне моделювання (Монте-Карло)").size(24),
+            row![
+                Text::new("Вибірки: "),
+                column![self
+                    .samples_input
+                    .view()
+                    .map(MonteCarloBlockMessage::SamplesMessage)]
+                .width(PARAMETER_INPUT_WIDTH),
+                Text::new("Зерно: "),
+                column![self
+                    .seed_input
+                    .view()
+                    .map(MonteCarloBlockMessage::SeedMessage)]
+                .width(PARAMETER_INPUT_WIDTH),
+                button("Симулювати").on_press(MonteCarloBlockMessage::SimulateButtonPressed),
+            ]
+            .spacing(10)
+            .align_items(iced::Alignment::Center),
+            Text::new("α критерію Гурвіца:"),
+            self.alpha_slider.view().map(MonteCarloBlockMessage::Alpha),
+        ]
+        .spacing(10);
+
+        if self.p.is_none() {
+            content = content.push(Text::new(
+                "Критерії в умовах ризику не моделюються: ймовірності не задано.",
+            ));
+        }
+        content = content.push(Text::new(
+            "Критерій максимізації ймовірності не моделюється: він залежить від обраного порогу.",
+        ));
+
+        if let Some(simulations) = &self.simulations {
+            let frequencies: Vec<Vec<f32>> = (0..self.cells.len())
+                .map(|row| {
+                    simulations
+                        .iter()
+                        .map(|simulation| simulation.win_frequencies[row])
+                        .collect()
+                })
+                .collect();
+
+            content = content.push(matrix_block(
+                &format!(
+                    "Частота вибору альтернативи ({}):",
+                    simulations
+                        .iter()
+                        .map(|simulation| simulation.criterion.to_string())
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                ),
+                &frequencies,
+                alternatives,
+            ));
+
+            let criteria: Vec<SimulatedCriterion> = simulations
+                .iter()
+                .map(|simulation| simulation.criterion)
+                .collect();
+            content = content.push(
+                row![
+                    Text::new("Розподіл оцінок за критерієм:"),
+                    pick_list(
+                        criteria,
+                        Some(self.selected_criterion),
+                        MonteCarloBlockMessage::CriterionSelected
+                    ),
+                ]
+                .spacing(10)
+                .align_items(iced::Alignment::Center),
+            );
+
+            if let Some(simulation) = simulations
+                .iter()
+                .find(|simulation| simulation.criterion == self.selected_criterion)
+            {
+                content = content.push(scores_view(simulation, alternatives));
+            }
+        }
+
+        if !self.error_text.is_empty() {
+            content = content.push(Text::new(self.error_text.clone()));
+        }
+
+        content.into()
+    }
+}
+
+/// Summary statistics and empirical distribution functions of scores.
+fn scores_view<'a>(
+    simulation: &CriterionSimulation,
+    alternatives: &[usize],
+) -> Element<'a, MonteCarloBlockMessage> {
+    let statistics: Vec<Vec<f32>> = (0..simulation.scores.len())
+        .map(|row| {
+            vec![
+                simulation.mean(row),
+                simulation.standard_deviation(row),
+                simulation.quantile(row, 0.05),
+                simulation.quantile(row, 0.5),
+                simulation.quantile(row, 0.95),
+            ]
+        })
+        .collect();
+
+    let series = simulation
+        .scores
+        .iter()
+        .enumerate()
+        .map(|(index, scores)| {
+            let step = (scores.len() / MONTE_CARLO_CHART_POINTS).max(1);
+            let points = scores
+                .iter()
+                .enumerate()
+                .step_by(step)
+                .chain(std::iter::once((
+                    scores.len() - 1,
+                    &scores[scores.len() - 1],
+                )))
+                .map(|(position, &score)| (score, (position + 1) as f32 / scores.len() as f32))
+                .collect();
+
+            Series::alternative(index, alternatives, points)
+        })
+        .collect();
+
+    column![
+        matrix_block("Середнє; σ; 5%; медіана; 95%:", &statistics, alternatives),
+        Text::new("Емпіричні функції розподілу оцінок"),
+        Chart::new(series, "Z").view(),
+    ]
+    .spacing(10)
+    .into()
+}
@@ -0,0 +1,267 @@
+use iced::{
+    widget::{checkbox, column, pick_list, row, Column, Text},
+    Element,
+};
+
+use crate::multi_criteria::{
+    best, pareto_front, rank, saw, saw_scores, topsis, vikor, Direction, TopsisTrace, VikorResult,
+};
+
+use super::outranking::{OutrankingBlock, OutrankingBlockMessage};
+use super::slider_block::{SliderBlock, SliderBlockMessage};
+use super::utils::{
+    all_alternatives, format_values, format_variants, gen_block, matrix_block, ranking_block,
+};
+
+/// Columns of the matrix are attributes with weights from the `p` row.
+pub struct MultiCriteriaBlock {
+    pub a: Vec<Vec<f32>>,
+    pub weights: Vec<f32>,
+    directions: Vec<Direction>,
+    pareto_front: Vec<usize>,
+    saw_scores: Vec<f32>,
+    saw_block: (f32, Vec<usize>),
+    topsis_trace: TopsisTrace,
+    topsis_block: (f32, Vec<usize>),
+    show_topsis_trace: bool,
+    outranking_block: OutrankingBlock,
+    vikor_slider: SliderBlock<f32>,
+    vikor: VikorResult,
+}
+
+#[derive(Clone, Debug)]
+pub enum MultiCriteriaBlockMessage {
+    DirectionSelected(usize, Direction),
+    TopsisTraceToggled(bool),
+    Outranking(OutrankingBlockMessage),
+    VikorSlider(SliderBlockMessage<f32>),
+}
+
+impl MultiCriteriaBlock {
+    pub fn new(a: Vec<Vec<f32>>, weights: Vec<f32>) -> Self {
+        let mut block = MultiCriteriaBlock {
+            directions: vec![Direction::Benefit; weights.len()],
+            a,
+            weights,
+            pareto_front: Vec::new(),
+            saw_scores: Vec::new(),
+            saw_block: (0.0, Vec::new()),
+            topsis_trace: TopsisTrace::default(),
+            topsis_block: (0.0, Vec::new()),
+            show_topsis_trace: false,
+            outranking_block: OutrankingBlock::new(),
+            vikor_slider: SliderBlock::new(0.5, 0.01, 0.0..=1.0),
+            vikor: VikorResult::default(),
+        };
+        block.update_results();
+
+        block
+    }
+
+    /// Directions are kept while the number of attributes stays the same.
+    pub fn update_data(&mut self, a: Vec<Vec<f32>>, weights: Vec<f32>) {
+        self.directions.resize(weights.len(), Direction::Benefit);
+        self.a = a;
+        self.weights = weights;
+
+        self.update_results();
+    }
+
+    pub fn update(&mut self, message: MultiCriteriaBlockMessage) {
+        match message {
+            MultiCriteriaBlockMessage::DirectionSelected(col, direction) => {
+                self.directions[col] = direction
+            }
+            MultiCriteriaBlockMessage::TopsisTraceToggled(show_topsis_trace) => {
+                self.show_topsis_trace = show_topsis_trace;
+                return;
+            }
+            MultiCriteriaBlockMessage::Outranking(outranking_block_message) => {
+                self.outranking_block.update(outranking_block_message);
+                return;
+            }
+            MultiCriteriaBlockMessage::VikorSlider(SliderBlockMessage::AlphaChange(v)) => {
+                self.vikor_slider.value = v;
+                self.update_vikor();
+                return;
+            }
+        }
+
+        self.update_results();
+    }
+
+    fn update_results(&mut self) {
+        self.pareto_front = pareto_front(&self.a, &self.directions);
+        self.saw_scores = saw_scores(&self.a, &self.weights, &self.directions);
+        self.saw_block = saw(&self.a, &self.weights, &self.directions);
+
+        self.topsis_trace = topsis(&self.a, &self.weights, &self.directions);
+        self.topsis_block = best(&self.topsis_trace.closeness);
+
+        self.outranking_block
+            .update_data(&self.a, &self.weights, &self.directions);
+
+        self.update_vikor();
+    }
+
+    fn update_vikor(&mut self) {
+        self.vikor = vikor(
+            &self.a,
+            &self.weights,
+            &self.directions,
+            self.vikor_slider.value,
+        );
+    }
+
+    pub fn view(&self) -> Element<'_, MultiCriteriaBlockMessage> {
+        let alternatives = &all_alternatives(self.a.len());
+        let directions = self
+            .directions
+            .iter()
+            .enumerate()
+            .map(|(col, direction)| {
+                row![
+                    Text::new(format!("y{} (w = {:.4}): ", col + 1, self.weights[col])),
+                    pick_list(&Direction::ALL[..], Some(*direction), move |direction| {
+                        MultiCriteriaBlockMessage::DirectionSelected(col, direction)
+                    }),
+                ]
+                .align_items(iced::Alignment::Center)
+                .into()
+            })
+            .collect();
+
+        column![
+            column![
+                Text::new("Напрями атрибутів").height(20),
+                Column::with_children(directions).spacing(5),
+            ]
+            .spacing(10),
+            column![
+                Text::new("Множина Парето").height(20),
+                Text::new(format!(
+                    "Варіанти: {}",
+                    format_variants(&self.pareto_front, alternatives)
+                )),
+            ]
+            .spacing(10),
+            column![
+                Text::new("Зважена сума (SAW)").height(20),
+                ranking_block(&self.saw_scores, &rank(&self.saw_scores), alternatives),
+                gen_block(
+                    "Найкраща альтернатива",
+                    self.saw_block.0,
+                    &self.saw_block.1,
+                    alternatives
+                ),
+            ]
+            .spacing(10),
+            self.topsis_view(),
+            self.outranking_block
+                .view()
+                .map(MultiCriteriaBlockMessage::Outranking),
+            self.vikor_view(),
+        ]
+        .spacing(40)
+        .into()
+    }
+
+    fn topsis_view(&self) -> Element<'_, MultiCriteriaBlockMessage> {
+        let trace = &self.topsis_trace;
+        let alternatives = &all_alternatives(self.a.len());
+
+        let mut content = column![
+            Text::new("TOPSIS").height(20),
+            checkbox(
+                "Показати проміжні кроки",
+                self.show_topsis_trace,
+                MultiCriteriaBlockMessage::TopsisTraceToggled
+            ),
+        ]
+        .spacing(10);
+
+        if self.show_topsis_trace {
+            let distances: Vec<Vec<f32>> = trace
+                .ideal_distances
+                .iter()
+                .zip(trace.anti_ideal_distances.iter())
+                .map(|(d_plus, d_minus)| vec![*d_plus, *d_minus])
+                .collect();
+
+            content = content
+                .push(matrix_block(
+                    "Нормалізована матриця:",
+                    &trace.normalized,
+                    alternatives,
+                ))
+                .push(matrix_block(
+                    "Зважена матриця:",
+                    &trace.weighted,
+                    alternatives,
+                ))
+                .push(Text::new(format!("A+ = ({})", format_values(&trace.ideal))))
+                .push(Text::new(format!(
+                    "A- = ({})",
+                    format_values(&trace.anti_ideal)
+                )))
+                .push(matrix_block("Відстані D+; D-:", &distances, alternatives));
+        }
+
+        content
+            .push(Text::new("Коефіцієнти близькості C:"))
+            .push(ranking_block(
+                &trace.closeness,
+                &rank(&trace.closeness),
+                alternatives,
+            ))
+            .push(gen_block(
+                "Найкраща альтернатива",
+                self.topsis_block.0,
+                &self.topsis_block.1,
+                alternatives,
+            ))
+            .into()
+    }
+
+    fn vikor_view(&self) -> Element<'_, MultiCriteriaBlockMessage> {
+        let result = &self.vikor;
+        let alternatives = &all_alternatives(self.a.len());
+
+        let indices: Vec<Vec<f32>> = (0..result.q.len())
+            .map(|row| vec![result.s[row], result.r[row], result.q[row]])
+            .collect();
+
+        let check = |passed: bool| {
+            if passed {
+                "виконується"
+            } else {
+                "не виконується"
+            }
+        };
+
+        column![
+            Text::new("VIKOR").height(20),
+            Text::new("Вага стратегії більшості v:"),
+            self.vikor_slider
+                .view()
+                .map(MultiCriteriaBlockMessage::VikorSlider),
+            matrix_block("S; R; Q:", &indices, alternatives),
+            Text::new("Ранжування за Q (менше — краще):"),
+            ranking_block(&result.q, &result.order, alternatives),
+            Text::new(format!(
+                "Прийнятна перевага: {}",
+                check(result.acceptable_advantage)
+            )),
+            Text::new(format!(
+                "Прийнятна стабільність: {}",
+                check(result.acceptable_stability)
+            )),
+            Text::new(format!(
+                "Компромісні рішення: {}",
+                format_variants(&result.compromise, alternatives)
+            )),
+        ]
+        .spacing(10)
+        .into()
+    }
+}
@@ -0,0 +1,282 @@
+use iced::{
+    widget::{column, pick_list, row, Column, Text},
+    Element,
+};
+
+use crate::{
+    constants::PARAMETER_INPUT_WIDTH,
+    multi_criteria::{rank, Direction},
+    outranking::{
+        electre::{electre, ElectreResult},
+        promethee::{promethee, PreferenceFunction, PreferenceFunctionKind, PrometheeResult},
+    },
+    value_component::{ValueInput, ValueInputMessage},
+};
+
+use super::{
+    outranking_graph::OutrankingGraph,
+    utils::{all_alternatives, format_variants, matrix_block, ranking_block},
+};
+
+struct PreferenceFunctionInput {
+    kind: PreferenceFunctionKind,
+    q_input: ValueInput,
+    p_input: ValueInput,
+}
+
+impl PreferenceFunctionInput {
+    fn new() -> Self {
+        let mut q_input = ValueInput::new("q".to_string());
+        q_input.value = "0".to_string();
+        let mut p_input = ValueInput::new("p".to_string());
+        p_input.value = "1".to_string();
+
+        PreferenceFunctionInput {
+            kind: PreferenceFunctionKind::Usual,
+            q_input,
+            p_input,
+        }
+    }
+
+    fn parse(&self) -> Option<PreferenceFunction> {
+        let function = PreferenceFunction {
+            kind: self.kind,
+            q: self.q_input.value.parse().ok()?,
+            p: self.p_input.value.parse().ok()?,
+        };
+
+        function.is_valid().then_some(function)
+    }
+}
+
+/// ELECTRE I and PROMETHEE II over the decision matrix.
+pub struct OutrankingBlock {
+    a: Vec<Vec<f32>>,
+    weights: Vec<f32>,
+    directions: Vec<Direction>,
+    concordance_input: ValueInput,
+    discordance_input: ValueInput,
+    preference_functions: Vec<PreferenceFunctionInput>,
+    electre: Option<ElectreResult>,
+    promethee: Option<PrometheeResult>,
+}
+
+#[derive(Clone, Debug)]
+pub enum OutrankingBlockMessage {
+    ConcordanceMessage(ValueInputMessage),
+    DiscordanceMessage(ValueInputMessage),
+    PreferenceKindSelected(usize, PreferenceFunctionKind),
+    IndifferenceMessage(usize, ValueInputMessage),
+    PreferenceMessage(usize, ValueInputMessage),
+}
+
+impl OutrankingBlock {
+    pub fn new() -> Self {
+        let mut concordance_input = ValueInput::new("c*".to_string());
+        concordance_input.value = "0.6".to_string();
+        let mut discordance_input = ValueInput::new("d*".to_string());
+        discordance_input.value = "0.4".to_string();
+
+        OutrankingBlock {
+            a: Vec::new(),
+            weights: Vec::new(),
+            directions: Vec::new(),
+            concordance_input,
+            discordance_input,
+            preference_functions: Vec::new(),
+            electre: None,
+            promethee: None,
+        }
+    }
+
+    /// Thresholds and preference functions are kept while the number of
+    /// attributes stays the same.
+    pub fn update_data(&mut self, a: &[Vec<f32>], weights: &[f32], directions: &[Direction]) {
+        self.a = a.to_vec();
+        self.weights = weights.to_vec();
+        self.directions = directions.to_vec();
+        self.preference_functions
+            .resize_with(weights.len(), PreferenceFunctionInput::new);
+
+        self.update_results();
+    }
+
+    pub fn update(&mut self, message: OutrankingBlockMessage) {
+        match message {
+            OutrankingBlockMessage::ConcordanceMessage(ValueInputMessage::ValueChanged(value)) => {
+                self.concordance_input.value = value
+            }
+            OutrankingBlockMessage::DiscordanceMessage(ValueInputMessage::ValueChanged(value)) => {
+                self.discordance_input.value = value
+            }
+            OutrankingBlockMessage::PreferenceKindSelected(col, kind) => {
+                self.preference_functions[col].kind = kind
+            }
+            OutrankingBlockMessage::IndifferenceMessage(
+                col,
+                ValueInputMessage::ValueChanged(value),
+            ) => self.preference_functions[col].q_input.value = value,
+            OutrankingBlockMessage::PreferenceMessage(
+                col,
+                ValueInputMessage::ValueChanged(value),
+            ) => self.preference_functions[col].p_input.value = value,
+        }
+
+        self.update_results();
+    }
+
+    /// Invalid parameters hide the results of the corresponding method.
+    fn update_results(&mut self) {
+        let thresholds = (
+            self.concordance_input.value.parse::<f32>(),
+            self.discordance_input.value.parse::<f32>(),
+        );
+
+        self.electre = match thresholds {
+            (Ok(concordance), Ok(discordance))
+                if (0.0..=1.0).contains(&concordance) && (0.0..=1.0).contains(&discordance) =>
+            {
+                Some(electre(
+                    &self.a,
+                    &self.weights,
+                    &self.directions,
+                    concordance,
+                    discordance,
+                ))
+            }
+            _ => None,
+        };
+
+        self.promethee = self
+            .preference_functions
+            .iter()
+            .map(PreferenceFunctionInput::parse)
+            .collect::<Option<Vec<_>>>()
+            .map(|functions| promethee(&self.a, &self.weights, &self.directions, &functions));
+    }
+
+    pub fn view(&self) -> Element<'_, OutrankingBlockMessage> {
+        column![self.electre_view(), self.promethee_view()]
+            .spacing(40)
+            .into()
+    }
+
+    fn electre_view(&self) -> Element<'_, OutrankingBlockMessage> {
+        let alternatives = &all_alternatives(self.a.len());
+        let mut content = column![
+            Text::new("ELECTRE I").height(20),
+            row![
+                Text::new("Поріг згоди c* = "),
+                self.concordance_input
+                    .view()
+                    .map(OutrankingBlockMessage::ConcordanceMessage),
+                Text::new("Поріг незгоди d* = "),
+                self.discordance_input
+                    .view()
+                    .map(OutrankingBlockMessage::DiscordanceMessage),
+            ]
+            .align_items(iced::Alignment::Center),
+        ]
+        .spacing(10);
+
+        match &self.electre {
+            Some(result) => {
+                content = content
+                    .push(matrix_block(
+                        "Індекси згоди:",
+                        &result.concordance,
+                        alternatives,
+                    ))
+                    .push(matrix_block(
+                        "Індекси незгоди:",
+                        &result.discordance,
+                        alternatives,
+                    ))
+                    .push(
+                        OutrankingGraph::new(
+                            result.outranks.clone(),
+                            result.kernel.clone().unwrap_or_default(),
+                        )
+                        .view(),
+                    )
+                    .push(Text::new(match &result.kernel {
+                        Some(kernel) => format!("Ядро: {}", format_variants(kernel, alternatives)),
+                        None => "Граф переваг містить цикли, ядро не визначене".to_string(),
+                    }));
+            }
+            None => {
+                content = content.push(Text::new("Пороги мають бути числами з [0, 1]."));
+            }
+        }
+
+        content.into()
+    }
+
+    fn promethee_view(&self) -> Element<'_, OutrankingBlockMessage> {
+        let alternatives = &all_alternatives(self.a.len());
+        let functions = self
+            .preference_functions
+            .iter()
+            .enumerate()
+            .map(|(col, function)| {
+                row![
+                    Text::new(format!("y{}: ", col + 1)),
+                    pick_list(
+                        &PreferenceFunctionKind::ALL[..],
+                        Some(function.kind),
+                        move |kind| OutrankingBlockMessage::PreferenceKindSelected(col, kind)
+                    ),
+                    column![function.q_input.view().map(move |message| {
+                        OutrankingBlockMessage::IndifferenceMessage(col, message)
+                    })]
+                    .width(PARAMETER_INPUT_WIDTH),
+                    column![function.p_input.view().map(move |message| {
+                        OutrankingBlockMessage::PreferenceMessage(col, message)
+                    })]
+                    .width(PARAMETER_INPUT_WIDTH),
+                ]
+                .align_items(iced::Alignment::Center)
+                .into()
+            })
+            .collect();
+
+        let mut content = column![
+            Text::new("PROMETHEE II").height(20),
+            Text::new("Функції переваги (q — поріг байдужості, p — поріг переваги):"),
+            Column::with_children(functions).spacing(5),
+        ]
+        .spacing(10);
+
+        match &self.promethee {
+            Some(result) => {
+                let flows: Vec<Vec<f32>> = result
+                    .positive_flows
+                    .iter()
+                    .zip(result.negative_flows.iter())
+                    .map(|(positive, negative)| vec![*positive, *negative])
+                    .collect();
+
+                content = content
+                    .push(matrix_block(
+                        "Агреговані переваги π:",
+                        &result.preferences,
+                        alternatives,
+                    ))
+                    .push(matrix_block("Потоки Φ+; Φ-:", &flows, alternatives))
+                    .push(Text::new("Чисті потоки Φ:"))
+                    .push(ranking_block(
+                        &result.net_flows,
+                        &rank(&result.net_flows),
+                        alternatives,
+                    ));
+            }
+            None => {
+                content = content.push(Text::new(
+                    "Перевірте пороги функцій переваги: q ≥ 0, p > 0, для рівневої та лінійної q < p.",
+                ));
+            }
+        }
+
+        content.into()
+    }
+}
@@ -0,0 +1,110 @@
+use std::f32::consts::PI;
+
+use iced::{
+    alignment, mouse,
+    widget::{
+        canvas::{self, Frame, Geometry, Path, Stroke},
+        text::Shaping,
+        Canvas,
+    },
+    Element, Length, Point, Rectangle, Renderer, Theme, Vector,
+};
+
+use crate::constants::{GRAPH_NODE_RADIUS, GRAPH_SIZE, SERIES_COLORS};
+
+/// Alternatives placed on a circle with arrows from outranking to outranked
+/// ones. Highlighted alternatives (e.g. the kernel) are filled.
+pub struct OutrankingGraph {
+    outranks: Vec<Vec<bool>>,
+    highlighted: Vec<usize>,
+}
+
+impl OutrankingGraph {
+    pub fn new(outranks: Vec<Vec<bool>>, highlighted: Vec<usize>) -> Self {
+        OutrankingGraph {
+            outranks,
+            highlighted,
+        }
+    }
+
+    pub fn view<'a, Message: 'a>(self) -> Element<'a, Message> {
+        Canvas::new(self)
+            .width(Length::Fixed(GRAPH_SIZE))
+            .height(Length::Fixed(GRAPH_SIZE))
+            .into()
+    }
+
+    fn node_position(&self, bounds: &Rectangle, index: usize) -> Point {
+        let center = Point::new(bounds.width / 2.0, bounds.height / 2.0);
+        let radius = bounds.width.min(bounds.height) / 2.0 - 2.0 * GRAPH_NODE_RADIUS;
+        let angle = 2.0 * PI * index as f32 / self.outranks.len() as f32 - PI / 2.0;
+
+        center + Vector::new(radius * angle.cos(), radius * angle.sin())
+    }
+}
+
+impl<Message> canvas::Program<Message> for OutrankingGraph {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let text_color = theme.palette().text;
+        let edge_stroke = Stroke::default().with_color(text_color).with_width(1.0);
+
+        for (x, row) in self.outranks.iter().enumerate() {
+            for (y, _) in row.iter().enumerate().filter(|(_, &outranks)| outranks) {
+                let from = self.node_position(&bounds, x);
+                let to = self.node_position(&bounds, y);
+
+                let length = from.distance(to);
+                if length <= 2.0 * GRAPH_NODE_RADIUS {
+                    continue;
+                }
+                let direction = Vector::new((to.x - from.x) / length, (to.y - from.y) / length);
+
+                let start = from + direction * GRAPH_NODE_RADIUS;
+                let end = to - direction * GRAPH_NODE_RADIUS;
+                frame.stroke(&Path::line(start, end), edge_stroke.clone());
+
+                let normal = Vector::new(-direction.y, direction.x);
+                let arrow = Path::new(|builder| {
+                    builder.move_to(end);
+                    builder.line_to(end - direction * 10.0 + normal * 4.0);
+                    builder.line_to(end - direction * 10.0 - normal * 4.0);
+                    builder.close();
+                });
+                frame.fill(&arrow, text_color);
+            }
+        }
+
+        for index in 0..self.outranks.len() {
+            let position = self.node_position(&bounds, index);
+            let node = Path::circle(position, GRAPH_NODE_RADIUS);
+
+            if self.highlighted.contains(&index) {
+                frame.fill(&node, SERIES_COLORS[0]);
+            }
+            frame.stroke(&node, edge_stroke.clone());
+
+            frame.fill_text(canvas::Text {
+                content: format!("Z_{}", index + 1),
+                position,
+                color: text_color,
+                size: 12.0,
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping: Shaping::Advanced,
+                ..canvas::Text::default()
+            });
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
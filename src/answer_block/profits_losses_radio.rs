@@ -27,7 +27,7 @@ impl ProfitsLossesRadio {
         }
     }
 
-    pub fn view(&self) -> Element<ProfitsLossesRadioMessage> {
+    pub fn view(&self) -> Element<'_, ProfitsLossesRadioMessage> {
         column![
             Radio::new(
                 "Прибутки",
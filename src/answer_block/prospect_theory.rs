@@ -0,0 +1,166 @@
+use iced::{
+    widget::{column, row, Text},
+    Element,
+};
+
+use crate::{
+    constants::PARAMETER_INPUT_WIDTH,
+    prospect_theory::{prospect_theory, prospect_values, ProspectTheory},
+    value_component::{ValueInput, ValueInputMessage},
+};
+
+use super::{
+    slider_block::{SliderBlock, SliderBlockMessage},
+    utils::{format_values, gen_block},
+};
+
+/// Cumulative prospect theory criterion with parameters on sliders.
+pub struct ProspectTheoryBlock {
+    a: Vec<Vec<f32>>,
+    p: Vec<f32>,
+    reference_point_input: ValueInput,
+    alpha_slider: SliderBlock<f32>,
+    beta_slider: SliderBlock<f32>,
+    lambda_slider: SliderBlock<f32>,
+    gamma_slider: SliderBlock<f32>,
+    values: Vec<f32>,
+    best: Option<(f32, Vec<usize>)>,
+}
+
+#[derive(Clone, Debug)]
+pub enum ProspectTheoryBlockMessage {
+    ReferencePointMessage(ValueInputMessage),
+    Alpha(SliderBlockMessage<f32>),
+    Beta(SliderBlockMessage<f32>),
+    Lambda(SliderBlockMessage<f32>),
+    Gamma(SliderBlockMessage<f32>),
+}
+
+impl ProspectTheoryBlock {
+    pub fn new(a: Vec<Vec<f32>>, p: Vec<f32>) -> Self {
+        let defaults = ProspectTheory::default();
+        let mut reference_point_input = ValueInput::new("Точка відліку".to_string());
+        reference_point_input.value = defaults.reference_point.to_string();
+
+        let mut block = ProspectTheoryBlock {
+            a,
+            p,
+            reference_point_input,
+            alpha_slider: SliderBlock::new(defaults.alpha, 0.01, 0.1..=1.0),
+            beta_slider: SliderBlock::new(defaults.beta, 0.01, 0.1..=1.0),
+            lambda_slider: SliderBlock::new(defaults.lambda, 0.05, 1.0..=5.0),
+            gamma_slider: SliderBlock::new(defaults.gamma, 0.01, 0.3..=1.0),
+            values: Vec::new(),
+            best: None,
+        };
+        block.update_results();
+
+        block
+    }
+
+    pub fn update_data(&mut self, a: Vec<Vec<f32>>, p: Vec<f32>) {
+        self.a = a;
+        self.p = p;
+
+        self.update_results();
+    }
+
+    pub fn update(&mut self, message: ProspectTheoryBlockMessage) {
+        match message {
+            ProspectTheoryBlockMessage::ReferencePointMessage(ValueInputMessage::ValueChanged(
+                value,
+            )) => self.reference_point_input.value = value,
+            ProspectTheoryBlockMessage::Alpha(SliderBlockMessage::AlphaChange(value)) => {
+                self.alpha_slider.value = value
+            }
+            ProspectTheoryBlockMessage::Beta(SliderBlockMessage::AlphaChange(value)) => {
+                self.beta_slider.value = value
+            }
+            ProspectTheoryBlockMessage::Lambda(SliderBlockMessage::AlphaChange(value)) => {
+                self.lambda_slider.value = value
+            }
+            ProspectTheoryBlockMessage::Gamma(SliderBlockMessage::AlphaChange(value)) => {
+                self.gamma_slider.value = value
+            }
+        }
+
+        self.update_results();
+    }
+
+    /// Invalid reference point hides the result.
+    fn update_results(&mut self) {
+        match self.reference_point_input.value.parse::<f32>() {
+            Ok(reference_point) => {
+                let theory = ProspectTheory {
+                    reference_point,
+                    alpha: self.alpha_slider.value,
+                    beta: self.beta_slider.value,
+                    lambda: self.lambda_slider.value,
+                    gamma: self.gamma_slider.value,
+                };
+
+                self.values = prospect_values(&self.a, &self.p, &theory);
+                self.best = Some(prospect_theory(&self.a, &self.p, &theory));
+            }
+            Err(_) => {
+                self.values = Vec::new();
+                self.best = None;
+            }
+        }
+    }
+
+    pub fn view(&self, alternatives: &[usize]) -> Element<'_, ProspectTheoryBlockMessage> {
+        let mut content = column![
+            Text::new("Теорія перспектив").height(20),
+            row![
+                Text::new("Точка відліку r ="),
+                column![self
+                    .reference_point_input
+                    .view()
+                    .map(ProspectTheoryBlockMessage::ReferencePointMessage)]
+                .width(PARAMETER_INPUT_WIDTH),
+            ]
+            .spacing(10)
+            .align_items(iced::Alignment::Center),
+            Text::new("v(x) = (x − r)^α, якщо x ≥ r, інакше −λ (r − x)^β"),
+            Text::new("α:"),
+            self.alpha_slider
+                .view()
+                .map(ProspectTheoryBlockMessage::Alpha),
+            Text::new("β:"),
+            self.beta_slider
+                .view()
+                .map(ProspectTheoryBlockMessage::Beta),
+            Text::new("λ:"),
+            self.lambda_slider
+                .view()
+                .map(ProspectTheoryBlockMessage::Lambda),
+            Text::new("w(p) = p^γ / (p^γ + (1 − p)^γ)^(1/γ), γ:"),
+            self.gamma_slider
+                .view()
+                .map(ProspectTheoryBlockMessage::Gamma),
+        ]
+        .spacing(10);
+
+        match &self.best {
+            Some(best) => {
+                content = content
+                    .push(Text::new(format!(
+                        "Цінності перспектив: {}",
+                        format_values(&self.values)
+                    )))
+                    .push(gen_block(
+                        "Найкраща альтернатива",
+                        best.0,
+                        &best.1,
+                        alternatives,
+                    ));
+            }
+            None => {
+                content = content.push(Text::new("Точка відліку має бути числом."));
+            }
+        }
+
+        content.into()
+    }
+}
@@ -5,35 +5,65 @@ use iced::Element;
 
 use crate::criterion::{
     get_max, get_min,
-    risk_condition::{bayes, dispersion_minimization, modal, probability_maximization},
+    risk_condition::{
+        bayes, bayes_probability_ranges, bayes_switching_points, dispersion_minimization, evpi,
+        exceedance_probabilities, expected_opportunity_losses, expected_value_under_certainty,
+        modal, probability_maximization, probability_maximization_breakpoints,
+    },
 };
 
-use super::utils::generate_variants_block;
 use super::{
+    chart::{Chart, Series},
+    downside_risk::{DownsideRiskBlock, DownsideRiskBlockMessage},
+    prospect_theory::{ProspectTheoryBlock, ProspectTheoryBlockMessage},
+    risk_measures::{RiskMeasuresBlock, RiskMeasuresBlockMessage},
+    simplex_chart::SimplexChart,
     slider_block::{SliderBlock, SliderBlockMessage},
-    utils::gen_block,
+    stochastic_dominance::StochasticDominanceBlock,
+    utility::{UtilityBlock, UtilityBlockMessage},
+    utils::{alternative_label, gen_block, generate_variants_block},
 };
 
 pub struct RiskConditionAnswerBlocks {
     pub a: Vec<Vec<f32>>,
     pub p: Vec<f32>,
+    /// Original numbers of the rows of `a`, dominated rows may be pruned.
+    pub alternatives: Vec<usize>,
     bayes_block: (f32, Vec<usize>),
+    bayes_probability_ranges: Vec<(f32, f32)>,
+    evpi_block: (f32, Vec<usize>),
+    expected_value_under_certainty: f32,
+    expected_opportunity_losses: Vec<f32>,
     dispersion_minimization_block: (f32, Vec<usize>),
     probability_maximization_block: Option<(f32, Vec<usize>)>,
     modal_block: Option<(f32, Vec<usize>)>,
+    probability_maximization_breakpoints: Vec<f32>,
     pub probability_maximization_slider: SliderBlock<f32>,
+    pub utility_block: UtilityBlock,
+    pub risk_measures_block: RiskMeasuresBlock,
+    pub downside_risk_block: DownsideRiskBlock,
+    stochastic_dominance_block: StochasticDominanceBlock,
+    pub prospect_theory_block: ProspectTheoryBlock,
 }
 
 #[derive(Clone, Debug)]
 pub enum RiskConditionAnswerBlockMessage {
     Alpha(SliderBlockMessage<f32>),
+    Utility(UtilityBlockMessage),
+    RiskMeasures(RiskMeasuresBlockMessage),
+    DownsideRisk(DownsideRiskBlockMessage),
+    ProspectTheory(ProspectTheoryBlockMessage),
 }
 
 impl RiskConditionAnswerBlocks {
-    pub fn new(a: Vec<Vec<f32>>, p: Vec<f32>) -> Self {
+    pub fn new(a: Vec<Vec<f32>>, p: Vec<f32>, alternatives: Vec<usize>) -> Self {
         let bayes_block = bayes(&a, &p);
+        let bayes_probability_ranges = bayes_probability_ranges(&a, &p);
+        let evpi_block = evpi(&a, &p);
+        let expected_value_under_certainty = expected_value_under_certainty(&a, &p);
+        let expected_opportunity_losses = expected_opportunity_losses(&a, &p);
         let dispersion_minimization_block = dispersion_minimization(&a, &p);
-        let probability_maximization_block = if a.len() > 0 && a[0].len() > 0 {
+        let probability_maximization_block = if !a.is_empty() && !a[0].is_empty() {
             probability_maximization(&a, &p, Some(a[0][0]))
         } else {
             probability_maximization(&a, &p, None)
@@ -42,58 +72,160 @@ impl RiskConditionAnswerBlocks {
 
         let probability_maximization_slider = if probability_maximization_block.is_some() {
             let range = get_probability_maximization_slider_range(&a);
-            SliderBlock::new(*range.start(), 1.0, range)
+            let step = get_probability_maximization_slider_step(&range);
+            SliderBlock::new(*range.start(), step, range)
         } else {
             SliderBlock::new(0.0, 1.0, 0.0..=10.0) // default (not be used)
         };
 
+        let probability_maximization_breakpoints = probability_maximization_breakpoints(&a);
+        let utility_block = UtilityBlock::new(a.clone(), p.clone());
+        let risk_measures_block = RiskMeasuresBlock::new(a.clone(), p.clone());
+        let downside_risk_block = DownsideRiskBlock::new(a.clone(), p.clone());
+        let stochastic_dominance_block = StochasticDominanceBlock::new(&a, &p);
+        let prospect_theory_block = ProspectTheoryBlock::new(a.clone(), p.clone());
+
         RiskConditionAnswerBlocks {
             a,
             p,
+            alternatives,
             bayes_block,
+            bayes_probability_ranges,
+            evpi_block,
+            expected_value_under_certainty,
+            expected_opportunity_losses,
             dispersion_minimization_block,
             probability_maximization_block,
             modal_block,
+            probability_maximization_breakpoints,
             probability_maximization_slider,
+            utility_block,
+            risk_measures_block,
+            downside_risk_block,
+            stochastic_dominance_block,
+            prospect_theory_block,
         }
     }
 
-    pub fn view(&self) -> Element<RiskConditionAnswerBlockMessage> {
+    pub fn view(&self) -> Element<'_, RiskConditionAnswerBlockMessage> {
+        let alternatives = &self.alternatives;
+
         let mut content = column![
-            gen_block::<RiskConditionAnswerBlockMessage>(
-                "Байєса",
-                self.bayes_block.0,
-                &self.bayes_block.1
-            ),
+            self.stochastic_dominance_block.view(alternatives),
+            column![
+                gen_block::<RiskConditionAnswerBlockMessage>(
+                    "Байєса",
+                    self.bayes_block.0,
+                    &self.bayes_block.1,
+                    alternatives
+                ),
+                self.bayes_sensitivity_view(alternatives),
+            ]
+            .spacing(10),
+            column![
+                Text::new("Цінність повної інформації").height(20),
+                Text::new(format!(
+                    "Очікуване значення за повної інформації = {:.4}",
+                    self.expected_value_under_certainty
+                )),
+                Text::new(format!("EVPI = {:.4}", self.evpi_block.0)),
+                Text::new(format!(
+                    "Очікувані втрати можливостей: {}",
+                    self.expected_opportunity_losses
+                        .iter()
+                        .enumerate()
+                        .map(|(index, loss)| {
+                            format!("{} = {loss:.4}", alternative_label(index, alternatives))
+                        })
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                )),
+                generate_variants_block(&self.evpi_block.1, alternatives),
+            ]
+            .spacing(10),
             gen_block(
                 "Мінімізація дисперсії",
                 self.dispersion_minimization_block.0,
-                &self.dispersion_minimization_block.1
+                &self.dispersion_minimization_block.1,
+                alternatives
             ),
+            self.downside_risk_block
+                .view(alternatives)
+                .map(RiskConditionAnswerBlockMessage::DownsideRisk),
         ];
 
-        if self.probability_maximization_block.is_some() {
-            let probability_maximization = self.probability_maximization_block.as_ref().unwrap();
-
+        if let Some(probability_maximization) = &self.probability_maximization_block {
             content = content.push(column![column![
                 Text::new("Максимізація ймовірнсоті").height(20),
                 self.probability_maximization_slider
                     .view()
-                    .map(move |message| RiskConditionAnswerBlockMessage::Alpha(message)),
+                    .map(RiskConditionAnswerBlockMessage::Alpha),
                 Text::new(format!("Z = {:.2}", probability_maximization.0)),
-                generate_variants_block(&probability_maximization.1)
+                generate_variants_block(&probability_maximization.1, alternatives),
+                self.probability_maximization_chart(alternatives).view(),
+                Text::new(format!(
+                    "Точки розриву: {}",
+                    self.probability_maximization_breakpoints
+                        .iter()
+                        .map(|b| b.to_string())
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                )),
             ]
             .spacing(10)]);
         }
 
-        if self.modal_block.is_some() {
-            let modal = self.modal_block.as_ref().unwrap();
-            content = content.push(gen_block("Модальний", modal.0, &modal.1));
+        if let Some(modal) = &self.modal_block {
+            content = content.push(gen_block("Модальний", modal.0, &modal.1, alternatives));
         }
 
+        content = content
+            .push(
+                self.risk_measures_block
+                    .view(alternatives)
+                    .map(RiskConditionAnswerBlockMessage::RiskMeasures),
+            )
+            .push(
+                self.utility_block
+                    .view(alternatives)
+                    .map(RiskConditionAnswerBlockMessage::Utility),
+            )
+            .push(
+                self.prospect_theory_block
+                    .view(alternatives)
+                    .map(RiskConditionAnswerBlockMessage::ProspectTheory),
+            );
+
         container(content.spacing(40)).into()
     }
 
+    pub fn update_data(&mut self, a: Vec<Vec<f32>>, p: Vec<f32>, alternatives: Vec<usize>) {
+        self.bayes_block = bayes(&a, &p);
+        self.bayes_probability_ranges = bayes_probability_ranges(&a, &p);
+        self.evpi_block = evpi(&a, &p);
+        self.expected_value_under_certainty = expected_value_under_certainty(&a, &p);
+        self.expected_opportunity_losses = expected_opportunity_losses(&a, &p);
+        self.dispersion_minimization_block = dispersion_minimization(&a, &p);
+        self.modal_block = modal(&a, &p);
+
+        let range = get_probability_maximization_slider_range(&a);
+        self.probability_maximization_slider
+            .set_step(get_probability_maximization_slider_step(&range));
+        self.probability_maximization_slider.set_range(range);
+        self.probability_maximization_breakpoints = probability_maximization_breakpoints(&a);
+        self.utility_block.update_data(a.clone(), p.clone());
+        self.risk_measures_block.update_data(a.clone(), p.clone());
+        self.downside_risk_block.update_data(a.clone(), p.clone());
+        self.stochastic_dominance_block.update_data(&a, &p);
+        self.prospect_theory_block.update_data(a.clone(), p.clone());
+
+        self.a = a;
+        self.p = p;
+        self.alternatives = alternatives;
+
+        self.update_probability_maximization_block();
+    }
+
     pub fn update_probability_maximization_block(&mut self) {
         self.probability_maximization_block = probability_maximization(
             &self.a,
@@ -101,11 +233,108 @@ impl RiskConditionAnswerBlocks {
             Some(self.probability_maximization_slider.value),
         );
     }
+
+    fn bayes_sensitivity_view(
+        &self,
+        alternatives: &[usize],
+    ) -> Element<'_, RiskConditionAnswerBlockMessage> {
+        let ranges = self
+            .bayes_probability_ranges
+            .iter()
+            .enumerate()
+            .map(|(index, (low, high))| format!("p{} ∈ [{:.4}; {:.4}]", index + 1, low, high))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut content = column![Text::new(format!(
+            "{} залишається оптимальним, якщо:\n{}",
+            alternative_label(self.bayes_block.1[0], alternatives),
+            ranges
+        ))]
+        .spacing(10);
+
+        match self.p.len() {
+            2 => {
+                let switching_points = bayes_switching_points(&self.a);
+
+                let series = self
+                    .a
+                    .iter()
+                    .enumerate()
+                    .map(|(index, row)| {
+                        Series::alternative(index, alternatives, vec![(0.0, row[1]), (1.0, row[0])])
+                    })
+                    .collect();
+
+                content = content.push(
+                    Chart::new(series, "p1")
+                        .vertical_markers(
+                            switching_points.iter().skip(1).map(|(p1, _)| *p1).collect(),
+                        )
+                        .highlighted_x(self.p[0])
+                        .view(),
+                );
+            }
+            3 => {
+                content = content.push(
+                    SimplexChart::new(self.a.clone(), self.p.clone(), alternatives.to_vec()).view(),
+                );
+            }
+            _ => {}
+        }
+
+        content.into()
+    }
+
+    /// Step chart of `P(outcome > b)` for every alternative.
+    fn probability_maximization_chart(&self, alternatives: &[usize]) -> Chart {
+        let breakpoints = &self.probability_maximization_breakpoints;
+        let (first, last) = (breakpoints[0], breakpoints[breakpoints.len() - 1]);
+        let padding = if last > first {
+            (last - first) * 0.05
+        } else {
+            1.0
+        };
+
+        let series = exceedance_probabilities(&self.a, &self.p, breakpoints)
+            .into_iter()
+            .enumerate()
+            .map(|(index, probabilities)| {
+                // Below the smallest value every outcome exceeds `b`.
+                let mut probability = self.p.iter().sum::<f32>();
+                let mut points = vec![(first - padding, probability)];
+
+                for (&b, &next_probability) in breakpoints.iter().zip(probabilities.iter()) {
+                    points.push((b, probability));
+                    points.push((b, next_probability));
+                    probability = next_probability;
+                }
+                points.push((last + padding, probability));
+
+                Series::alternative(index, alternatives, points)
+            })
+            .collect();
+
+        Chart::new(series, "b")
+            .vertical_markers(breakpoints.clone())
+            .highlighted_x(self.probability_maximization_slider.value)
+    }
 }
 
-pub fn get_probability_maximization_slider_range(a: &Vec<Vec<f32>>) -> RangeInclusive<f32> {
-    let min = get_min(&a.iter().map(|row| get_min(&row)).collect());
-    let max = get_max(&a.iter().map(|row| get_max(&row)).collect());
+pub fn get_probability_maximization_slider_range(a: &[Vec<f32>]) -> RangeInclusive<f32> {
+    let min = get_min(&a.iter().map(|row| get_min(row)).collect::<Vec<_>>());
+    let max = get_max(&a.iter().map(|row| get_max(row)).collect::<Vec<_>>());
 
     min..=max
 }
+
+/// Power of ten giving about a hundred slider steps over `range`.
+pub fn get_probability_maximization_slider_step(range: &RangeInclusive<f32>) -> f32 {
+    let span = range.end() - range.start();
+
+    if span > 0.0 {
+        10f32.powf((span / 100.0).log10().floor())
+    } else {
+        1.0
+    }
+}
@@ -0,0 +1,249 @@
+use iced::{
+    widget::{column, Text},
+    Element,
+};
+
+use crate::{
+    constants::SERIES_COLORS,
+    criterion::risk_condition::{bayes_scores, dispersion_scores},
+    risk_measures::{
+        coefficients_of_variation, conditional_value_at_risk, cvar_minimization, mean_cvar,
+        mean_minus_k_sigma, mean_variance_efficient, value_at_risk, var_minimization,
+        variation_minimization,
+    },
+};
+
+use super::{
+    chart::{Chart, Series},
+    slider_block::{SliderBlock, SliderBlockMessage},
+    utils::{alternative_label, format_variants, gen_block, matrix_block},
+};
+
+/// Downside risk measures of alternatives.
+pub struct RiskMeasuresBlock {
+    a: Vec<Vec<f32>>,
+    p: Vec<f32>,
+    confidence_slider: SliderBlock<f32>,
+    lambda_slider: SliderBlock<f32>,
+    value_at_risk: Vec<f32>,
+    conditional_value_at_risk: Vec<f32>,
+    var_block: (f32, Vec<usize>),
+    cvar_block: (f32, Vec<usize>),
+    mean_cvar_block: (f32, Vec<usize>),
+    k_slider: SliderBlock<f32>,
+    means: Vec<f32>,
+    standard_deviations: Vec<f32>,
+    coefficients_of_variation: Vec<Option<f32>>,
+    mean_variance_efficient: Vec<usize>,
+    variation_block: Option<(f32, Vec<usize>)>,
+    k_sigma_block: (f32, Vec<usize>),
+}
+
+#[derive(Clone, Debug)]
+pub enum RiskMeasuresBlockMessage {
+    Confidence(SliderBlockMessage<f32>),
+    Lambda(SliderBlockMessage<f32>),
+    K(SliderBlockMessage<f32>),
+}
+
+impl RiskMeasuresBlock {
+    pub fn new(a: Vec<Vec<f32>>, p: Vec<f32>) -> Self {
+        let mut block = RiskMeasuresBlock {
+            a,
+            p,
+            confidence_slider: SliderBlock::new(0.95, 0.01, 0.5..=0.99),
+            lambda_slider: SliderBlock::new(1.0, 0.1, 0.0..=5.0),
+            value_at_risk: Vec::new(),
+            conditional_value_at_risk: Vec::new(),
+            var_block: (0.0, Vec::new()),
+            cvar_block: (0.0, Vec::new()),
+            mean_cvar_block: (0.0, Vec::new()),
+            k_slider: SliderBlock::new(1.0, 0.1, 0.0..=3.0),
+            means: Vec::new(),
+            standard_deviations: Vec::new(),
+            coefficients_of_variation: Vec::new(),
+            mean_variance_efficient: Vec::new(),
+            variation_block: None,
+            k_sigma_block: (0.0, Vec::new()),
+        };
+        block.update_results();
+
+        block
+    }
+
+    pub fn update_data(&mut self, a: Vec<Vec<f32>>, p: Vec<f32>) {
+        self.a = a;
+        self.p = p;
+
+        self.update_results();
+    }
+
+    pub fn update(&mut self, message: RiskMeasuresBlockMessage) {
+        match message {
+            RiskMeasuresBlockMessage::Confidence(SliderBlockMessage::AlphaChange(value)) => {
+                self.confidence_slider.value = value
+            }
+            RiskMeasuresBlockMessage::Lambda(SliderBlockMessage::AlphaChange(value)) => {
+                self.lambda_slider.value = value
+            }
+            RiskMeasuresBlockMessage::K(SliderBlockMessage::AlphaChange(value)) => {
+                self.k_slider.value = value
+            }
+        }
+
+        self.update_results();
+    }
+
+    fn update_results(&mut self) {
+        let confidence = self.confidence_slider.value;
+
+        self.value_at_risk = value_at_risk(&self.a, &self.p, confidence);
+        self.conditional_value_at_risk = conditional_value_at_risk(&self.a, &self.p, confidence);
+        self.var_block = var_minimization(&self.a, &self.p, confidence);
+        self.cvar_block = cvar_minimization(&self.a, &self.p, confidence);
+        self.mean_cvar_block = mean_cvar(&self.a, &self.p, confidence, self.lambda_slider.value);
+
+        self.means = bayes_scores(&self.a, &self.p);
+        self.standard_deviations = dispersion_scores(&self.a, &self.p);
+        self.coefficients_of_variation = coefficients_of_variation(&self.a, &self.p);
+        self.mean_variance_efficient = mean_variance_efficient(&self.a, &self.p);
+        self.variation_block = variation_minimization(&self.a, &self.p);
+        self.k_sigma_block = mean_minus_k_sigma(&self.a, &self.p, self.k_slider.value);
+    }
+
+    pub fn view(&self, alternatives: &[usize]) -> Element<'_, RiskMeasuresBlockMessage> {
+        let measures: Vec<Vec<f32>> = self
+            .value_at_risk
+            .iter()
+            .zip(self.conditional_value_at_risk.iter())
+            .map(|(var, cvar)| vec![*var, *cvar])
+            .collect();
+
+        column![
+            column![
+                Text::new("VaR та CVaR").height(20),
+                Text::new("Рівень довіри:"),
+                self.confidence_slider
+                    .view()
+                    .map(RiskMeasuresBlockMessage::Confidence),
+                matrix_block(
+                    "VaR; CVaR (втрати, додатні значення — збиток):",
+                    &measures,
+                    alternatives
+                ),
+            ]
+            .spacing(10),
+            gen_block(
+                "Мінімізація VaR",
+                self.var_block.0,
+                &self.var_block.1,
+                alternatives
+            ),
+            gen_block(
+                "Мінімізація CVaR",
+                self.cvar_block.0,
+                &self.cvar_block.1,
+                alternatives
+            ),
+            column![
+                Text::new("Середнє — CVaR").height(20),
+                Text::new("Z = E − λ · CVaR, λ:"),
+                self.lambda_slider
+                    .view()
+                    .map(RiskMeasuresBlockMessage::Lambda),
+                gen_block(
+                    "Найкраща альтернатива",
+                    self.mean_cvar_block.0,
+                    &self.mean_cvar_block.1,
+                    alternatives
+                ),
+            ]
+            .spacing(10),
+            self.mean_variance_view(alternatives),
+        ]
+        .spacing(40)
+        .into()
+    }
+
+    fn mean_variance_view(&self, alternatives: &[usize]) -> Element<'_, RiskMeasuresBlockMessage> {
+        let coefficients = self
+            .coefficients_of_variation
+            .iter()
+            .enumerate()
+            .map(|(index, coefficient)| match coefficient {
+                Some(coefficient) => format!(
+                    "{} = {:.4}",
+                    alternative_label(index, alternatives),
+                    coefficient
+                ),
+                None => format!(
+                    "{} не визначено (E = 0)",
+                    alternative_label(index, alternatives)
+                ),
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        let mut content = column![
+            Text::new("Середнє — дисперсія").height(20),
+            self.mean_variance_chart(alternatives).view(),
+            Text::new(format!(
+                "Ефективні альтернативи: {}",
+                format_variants(&self.mean_variance_efficient, alternatives)
+            )),
+            Text::new(format!("Коефіцієнти варіації: {}", coefficients)),
+        ]
+        .spacing(10);
+
+        if let Some(variation_block) = &self.variation_block {
+            content = content.push(gen_block(
+                "Мінімізація коефіцієнта варіації",
+                variation_block.0,
+                &variation_block.1,
+                alternatives,
+            ));
+        }
+
+        content
+            .push(Text::new("Z = E − k · σ, k:"))
+            .push(self.k_slider.view().map(RiskMeasuresBlockMessage::K))
+            .push(gen_block(
+                "Найкраща альтернатива",
+                self.k_sigma_block.0,
+                &self.k_sigma_block.1,
+                alternatives,
+            ))
+            .into()
+    }
+
+    /// Alternatives in the `(σ, E)` plane with the efficient set connected.
+    fn mean_variance_chart(&self, alternatives: &[usize]) -> Chart {
+        let points: Vec<(f32, f32)> = self
+            .standard_deviations
+            .iter()
+            .copied()
+            .zip(self.means.iter().copied())
+            .collect();
+
+        let mut efficient_points: Vec<(f32, f32)> = self
+            .mean_variance_efficient
+            .iter()
+            .map(|&index| points[index])
+            .collect();
+        efficient_points.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap());
+
+        let mut series = vec![Series {
+            label: "Ефект.".to_string(),
+            points: efficient_points,
+            color: SERIES_COLORS[SERIES_COLORS.len() - 1],
+            width: 1.0,
+            markers: false,
+        }];
+        series.extend(points.iter().enumerate().map(|(index, &point)| Series {
+            markers: true,
+            ..Series::alternative(index, alternatives, vec![point])
+        }));
+
+        Chart::new(series, "σ")
+    }
+}
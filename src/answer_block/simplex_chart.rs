@@ -0,0 +1,149 @@
+use iced::{
+    alignment, mouse,
+    widget::{
+        canvas::{self, Frame, Geometry, Path},
+        text::Shaping,
+        Canvas,
+    },
+    Color, Element, Length, Point, Rectangle, Renderer, Theme,
+};
+
+use crate::{
+    constants::{
+        CHART_HEIGHT, CHART_LEGEND_WIDTH, CHART_MARGIN, SERIES_COLORS, SIMPLEX_RESOLUTION,
+    },
+    criterion::risk_condition::bayes,
+};
+
+use super::utils::alternative_label;
+
+/// Probability simplex of a three-state problem coloured by the
+/// Bayes-optimal alternative in every point.
+pub struct SimplexChart {
+    a: Vec<Vec<f32>>,
+    p: Vec<f32>,
+    alternatives: Vec<usize>,
+}
+
+impl SimplexChart {
+    pub fn new(a: Vec<Vec<f32>>, p: Vec<f32>, alternatives: Vec<usize>) -> Self {
+        SimplexChart { a, p, alternatives }
+    }
+
+    pub fn view<'a, Message: 'a>(self) -> Element<'a, Message> {
+        Canvas::new(self)
+            .width(Length::Fixed(CHART_HEIGHT + CHART_LEGEND_WIDTH))
+            .height(Length::Fixed(CHART_HEIGHT))
+            .into()
+    }
+
+    fn vertices(bounds: &Rectangle) -> [Point; 3] {
+        let side = bounds.height - 2.0 * CHART_MARGIN;
+        let height = side * 3f32.sqrt() / 2.0;
+        let top = CHART_MARGIN + (side - height) / 2.0;
+
+        [
+            Point::new(CHART_MARGIN, top + height),
+            Point::new(CHART_MARGIN + side, top + height),
+            Point::new(CHART_MARGIN + side / 2.0, top),
+        ]
+    }
+
+    fn to_screen(vertices: &[Point; 3], p: [f32; 3]) -> Point {
+        Point::new(
+            vertices.iter().zip(p).map(|(vertex, p)| vertex.x * p).sum(),
+            vertices.iter().zip(p).map(|(vertex, p)| vertex.y * p).sum(),
+        )
+    }
+}
+
+impl<Message> canvas::Program<Message> for SimplexChart {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let text_color = theme.palette().text;
+        let vertices = Self::vertices(&bounds);
+
+        let n = SIMPLEX_RESOLUTION;
+        let barycentric = |i: usize, j: usize| {
+            let (p1, p2) = (i as f32 / n as f32, j as f32 / n as f32);
+            [p1, p2, (1.0 - p1 - p2).max(0.0)]
+        };
+
+        for i in 0..n {
+            for j in 0..(n - i) {
+                let mut triangles = vec![[(i, j), (i + 1, j), (i, j + 1)]];
+                if i + j + 1 < n {
+                    triangles.push([(i + 1, j), (i + 1, j + 1), (i, j + 1)]);
+                }
+
+                for triangle in triangles {
+                    let corners = triangle.map(|(i, j)| barycentric(i, j));
+                    let centroid: Vec<f32> = (0..3)
+                        .map(|state| corners.iter().map(|corner| corner[state]).sum::<f32>() / 3.0)
+                        .collect();
+
+                    let optimal = bayes(&self.a, &centroid).1[0];
+                    let color = SERIES_COLORS[self.alternatives[optimal] % SERIES_COLORS.len()];
+
+                    let path = Path::new(|builder| {
+                        builder.move_to(Self::to_screen(&vertices, corners[0]));
+                        builder.line_to(Self::to_screen(&vertices, corners[1]));
+                        builder.line_to(Self::to_screen(&vertices, corners[2]));
+                        builder.close();
+                    });
+                    frame.fill(&path, Color { a: 0.6, ..color });
+                }
+            }
+        }
+
+        for (state, vertex) in vertices.iter().enumerate() {
+            frame.fill_text(canvas::Text {
+                content: format!("y{}", state + 1),
+                position: Point::new(vertex.x, vertex.y + if state == 2 { -4.0 } else { 4.0 }),
+                color: text_color,
+                size: 14.0,
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: if state == 2 {
+                    alignment::Vertical::Bottom
+                } else {
+                    alignment::Vertical::Top
+                },
+                shaping: Shaping::Advanced,
+                ..canvas::Text::default()
+            });
+        }
+
+        frame.fill(
+            &Path::circle(
+                Self::to_screen(&vertices, [self.p[0], self.p[1], self.p[2]]),
+                4.0,
+            ),
+            text_color,
+        );
+
+        for index in 0..self.a.len() {
+            frame.fill_text(canvas::Text {
+                content: alternative_label(index, &self.alternatives),
+                position: Point::new(
+                    bounds.width - CHART_LEGEND_WIDTH,
+                    CHART_MARGIN + 16.0 * index as f32,
+                ),
+                color: SERIES_COLORS[self.alternatives[index] % SERIES_COLORS.len()],
+                size: 14.0,
+                shaping: Shaping::Advanced,
+                ..canvas::Text::default()
+            });
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
@@ -32,7 +32,21 @@ where
         }
     }
 
-    pub fn view(&self) -> Element<SliderBlockMessage<T>> {
+    pub fn set_step(&mut self, step: T) {
+        self.step = step;
+    }
+
+    pub fn set_range(&mut self, range: RangeInclusive<T>) {
+        if self.value < *range.start() {
+            self.value = *range.start();
+        } else if self.value > *range.end() {
+            self.value = *range.end();
+        }
+
+        self.range = range;
+    }
+
+    pub fn view(&self) -> Element<'_, SliderBlockMessage<T>> {
         let slider = container(
             slider(
                 self.range.clone(),
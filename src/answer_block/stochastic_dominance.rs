@@ -0,0 +1,90 @@
+use iced::{
+    widget::{column, Column, Row, Text},
+    Element,
+};
+
+use crate::{
+    constants::{SERIES_COLORS, TEXT_CELL_WIDTH},
+    stochastic_dominance::{efficient, stochastic_dominance_matrix, StochasticDominance},
+};
+
+use super::utils::{alternative_label, format_variants};
+
+/// Pairwise first and second order stochastic dominance of alternatives.
+pub struct StochasticDominanceBlock {
+    matrix: Vec<Vec<Option<StochasticDominance>>>,
+    fsd_efficient: Vec<usize>,
+    ssd_efficient: Vec<usize>,
+}
+
+impl StochasticDominanceBlock {
+    pub fn new(a: &[Vec<f32>], p: &[f32]) -> Self {
+        let mut block = StochasticDominanceBlock {
+            matrix: Vec::new(),
+            fsd_efficient: Vec::new(),
+            ssd_efficient: Vec::new(),
+        };
+        block.update_data(a, p);
+
+        block
+    }
+
+    pub fn update_data(&mut self, a: &[Vec<f32>], p: &[f32]) {
+        self.matrix = stochastic_dominance_matrix(a, p);
+        self.fsd_efficient = efficient(&self.matrix, StochasticDominance::First);
+        self.ssd_efficient = efficient(&self.matrix, StochasticDominance::Second);
+    }
+
+    pub fn view<T: 'static>(&self, alternatives: &[usize]) -> Element<'static, T> {
+        let cell = |text: String| Text::new(text).width(TEXT_CELL_WIDTH);
+        // SSD-efficient alternatives are highlighted in the row labels.
+        let label = |row: usize| {
+            let text = cell(alternative_label(row, alternatives));
+
+            if self.ssd_efficient.contains(&row) {
+                text.style(SERIES_COLORS[2])
+            } else {
+                text
+            }
+        };
+
+        let header = Row::with_children(
+            std::iter::once(cell(String::new()).into())
+                .chain((0..self.matrix.len()).map(|col| label(col).into()))
+                .collect(),
+        );
+        let rows = self.matrix.iter().enumerate().map(|(row, relations)| {
+            Row::with_children(
+                std::iter::once(label(row).into())
+                    .chain(relations.iter().enumerate().map(|(col, relation)| {
+                        cell(match relation {
+                            _ if row == col => "·".to_string(),
+                            Some(StochasticDominance::First) => "FSD".to_string(),
+                            Some(StochasticDominance::Second) => "SSD".to_string(),
+                            None => "—".to_string(),
+                        })
+                        .into()
+                    }))
+                    .collect(),
+            )
+            .into()
+        });
+
+        column![
+            Text::new("Стохастичне домінування").height(20),
+            Text::new("Рядок домінує стовпець: FSD — першого порядку, SSD — другого"),
+            Column::with_children(std::iter::once(header.into()).chain(rows).collect()).spacing(5),
+            Text::new(format!(
+                "Ефективні за FSD: {}",
+                format_variants(&self.fsd_efficient, alternatives)
+            )),
+            Text::new(format!(
+                "Ефективні за SSD: {}",
+                format_variants(&self.ssd_efficient, alternatives)
+            ))
+            .style(SERIES_COLORS[2]),
+        ]
+        .spacing(10)
+        .into()
+    }
+}
@@ -1,23 +1,30 @@
 use crate::{
     constants::DEFAULT_PROFITS_LOSSES_CHOISE,
-    criterion::uncertainty::{hurwitz, maximax, minimax, savage},
+    criterion::{
+        get_max, get_min,
+        uncertainty::{hurwitz, hurwitz_switching_points, maximax, minimax, savage},
+    },
 };
 use iced::{
     widget::{column, Text},
-    Element,
+    Color, Element,
 };
 
 use super::{
+    chart::{Chart, Series},
     profits_losses_radio::{ProfitsLossesRadio, ProfitsLossesRadioMessage},
     slider_block::{SliderBlock, SliderBlockMessage},
-    utils::{gen_block, generate_variants_block},
+    utils::{format_variants, gen_block, generate_variants_block},
 };
 
 pub struct UncertaintyAnswerBlocks {
     pub a: Vec<Vec<f32>>,
+    /// Original numbers of the rows of `a`, dominated rows may be pruned.
+    alternatives: Vec<usize>,
     maximax_block: (f32, Vec<usize>),
     minimax_block: (f32, Vec<usize>),
     hurwitz_block: (f32, Vec<usize>),
+    hurwitz_switching_points: Vec<(f32, Vec<usize>)>,
     savage_block: (f32, Vec<usize>),
     pub hurwitz_slider: SliderBlock<f32>,
     pub profits_losses_radio: ProfitsLossesRadio,
@@ -30,47 +37,80 @@ pub enum UncertaintyAnswerBlocksMessage {
 }
 
 impl UncertaintyAnswerBlocks {
-    pub fn new(a: Vec<Vec<f32>>) -> Self {
+    pub fn new(a: Vec<Vec<f32>>, alternatives: Vec<usize>) -> Self {
         let (maximax_answer, maximax_indeces) = maximax(&a);
         let (minimax_answer, minimax_indeces) = minimax(&a, DEFAULT_PROFITS_LOSSES_CHOISE);
         let (hurwitz_answer, hurwitz_indeces) = hurwitz(&a, 0.5);
         let (savage_answer, savage_indeces) = savage(&a);
+        let hurwitz_switching_points = hurwitz_switching_points(&a);
 
         UncertaintyAnswerBlocks {
             a,
+            alternatives,
             maximax_block: (maximax_answer, maximax_indeces),
             minimax_block: (minimax_answer, minimax_indeces),
             hurwitz_block: (hurwitz_answer, hurwitz_indeces),
+            hurwitz_switching_points,
             hurwitz_slider: SliderBlock::new(0.5, 0.01, 0.0..=1.0),
             savage_block: (savage_answer, savage_indeces),
             profits_losses_radio: ProfitsLossesRadio::new(),
         }
     }
 
-    pub fn view(&self) -> Element<UncertaintyAnswerBlocksMessage> {
+    pub fn view(&self) -> Element<'_, UncertaintyAnswerBlocksMessage> {
+        let alternatives = &self.alternatives;
+
         column![
-            gen_block("Максімакс", self.maximax_block.0, &self.maximax_block.1,),
+            gen_block(
+                "Максімакс",
+                self.maximax_block.0,
+                &self.maximax_block.1,
+                alternatives
+            ),
             column![
                 self.profits_losses_radio.view().map(move |message| {
                     UncertaintyAnswerBlocksMessage::ProfitsLossesRadioChanged(message)
                 }),
-                gen_block("Мінімакс", self.minimax_block.0, &self.minimax_block.1,),
+                gen_block(
+                    "Мінімакс",
+                    self.minimax_block.0,
+                    &self.minimax_block.1,
+                    alternatives
+                ),
             ],
             column![column![
                 Text::new("Гурвіца").height(20),
                 self.hurwitz_slider
                     .view()
-                    .map(move |message| UncertaintyAnswerBlocksMessage::Alpha(message)),
+                    .map(UncertaintyAnswerBlocksMessage::Alpha),
                 Text::new(format!("Z = {:.2}", self.hurwitz_block.0)),
-                generate_variants_block(&self.hurwitz_block.1)
+                generate_variants_block(&self.hurwitz_block.1, alternatives),
+                self.hurwitz_chart(alternatives).view(),
+                Text::new(self.hurwitz_switching_points_text(alternatives)),
             ]
             .spacing(10)],
-            gen_block("Севіджа", self.savage_block.0, &self.savage_block.1,)
+            gen_block(
+                "Севіджа",
+                self.savage_block.0,
+                &self.savage_block.1,
+                alternatives
+            )
         ]
         .spacing(40)
         .into()
     }
 
+    pub fn update_data(&mut self, a: Vec<Vec<f32>>, alternatives: Vec<usize>) {
+        self.a = a;
+        self.alternatives = alternatives;
+
+        self.maximax_block = maximax(&self.a);
+        self.savage_block = savage(&self.a);
+        self.hurwitz_switching_points = hurwitz_switching_points(&self.a);
+        self.update_minimax();
+        self.update_hurwitz_block();
+    }
+
     pub fn update_hurwitz_block(&mut self) {
         self.hurwitz_block = hurwitz(&self.a, self.hurwitz_slider.value);
     }
@@ -78,4 +118,70 @@ impl UncertaintyAnswerBlocks {
     pub fn update_minimax(&mut self) {
         self.minimax_block = minimax(&self.a, self.profits_losses_radio.get_selected_choise())
     }
+
+    fn hurwitz_chart(&self, alternatives: &[usize]) -> Chart {
+        let lines: Vec<(f32, f32)> = self
+            .a
+            .iter()
+            .map(|row| (get_min(row), get_max(row)))
+            .collect();
+
+        let mut envelope: Vec<(f32, f32)> = self
+            .hurwitz_switching_points
+            .iter()
+            .map(|(alpha, indeces)| {
+                let (min_a, max_a) = lines[indeces[0]];
+                (*alpha, min_a + alpha * (max_a - min_a))
+            })
+            .collect();
+        if let Some((_, indeces)) = self.hurwitz_switching_points.last() {
+            envelope.push((1.0, lines[indeces[0]].1));
+        }
+
+        let mut series: Vec<Series> = lines
+            .iter()
+            .enumerate()
+            .map(|(index, &(min_a, max_a))| {
+                Series::alternative(index, alternatives, vec![(0.0, min_a), (1.0, max_a)])
+            })
+            .collect();
+        series.push(Series {
+            label: "max".to_string(),
+            points: envelope,
+            color: Color::BLACK,
+            width: 3.0,
+            markers: false,
+        });
+
+        Chart::new(series, "α")
+            .vertical_markers(
+                self.hurwitz_switching_points
+                    .iter()
+                    .skip(1)
+                    .map(|(alpha, _)| *alpha)
+                    .collect(),
+            )
+            .highlighted_x(self.hurwitz_slider.value)
+    }
+
+    fn hurwitz_switching_points_text(&self, alternatives: &[usize]) -> String {
+        if self.hurwitz_switching_points.len() < 2 {
+            return "Оптимальний варіант не змінюється для α ∈ [0; 1].".to_string();
+        }
+
+        let switches: Vec<_> = self
+            .hurwitz_switching_points
+            .windows(2)
+            .map(|window| {
+                format!(
+                    "α = {:.4}: {} → {}",
+                    window[1].0,
+                    format_variants(&window[0].1, alternatives),
+                    format_variants(&window[1].1, alternatives)
+                )
+            })
+            .collect();
+
+        format!("Точки перемикання:\n{}", switches.join("\n"))
+    }
 }
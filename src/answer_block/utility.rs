@@ -0,0 +1,188 @@
+use iced::{
+    widget::{column, pick_list, row, Text},
+    Element,
+};
+
+use crate::{
+    utility::{expected_utility, UtilityAnalysis, UtilityFunction, UtilityFunctionKind},
+    utils::parse_points,
+    value_component::{ValueInput, ValueInputMessage},
+};
+
+use super::utils::{gen_block, matrix_block};
+
+/// Expected utility criterion with a user-chosen utility function.
+pub struct UtilityBlock {
+    a: Vec<Vec<f32>>,
+    p: Vec<f32>,
+    kind: UtilityFunctionKind,
+    parameter_input: ValueInput,
+    points_input: ValueInput,
+    analysis: Option<UtilityAnalysis>,
+    error_text: String,
+}
+
+#[derive(Clone, Debug)]
+pub enum UtilityBlockMessage {
+    KindSelected(UtilityFunctionKind),
+    ParameterMessage(ValueInputMessage),
+    PointsMessage(ValueInputMessage),
+}
+
+impl UtilityBlock {
+    pub fn new(a: Vec<Vec<f32>>, p: Vec<f32>) -> Self {
+        let mut block = UtilityBlock {
+            a,
+            p,
+            kind: UtilityFunctionKind::Linear,
+            parameter_input: ValueInput::new("Параметр".to_string()),
+            points_input: ValueInput::new("x1:u1; x2:u2; ...".to_string()),
+            analysis: None,
+            error_text: String::new(),
+        };
+        block.update_analysis();
+
+        block
+    }
+
+    pub fn update_data(&mut self, a: Vec<Vec<f32>>, p: Vec<f32>) {
+        self.a = a;
+        self.p = p;
+
+        self.update_analysis();
+    }
+
+    pub fn update(&mut self, message: UtilityBlockMessage) {
+        match message {
+            UtilityBlockMessage::KindSelected(kind) => {
+                self.kind = kind;
+                self.parameter_input.value = match kind {
+                    UtilityFunctionKind::Exponential => "100",
+                    UtilityFunctionKind::Logarithmic => "1000",
+                    UtilityFunctionKind::Power => "0.5",
+                    _ => "",
+                }
+                .to_string();
+            }
+            UtilityBlockMessage::ParameterMessage(ValueInputMessage::ValueChanged(value)) => {
+                self.parameter_input.value = value
+            }
+            UtilityBlockMessage::PointsMessage(ValueInputMessage::ValueChanged(value)) => {
+                self.points_input.value = value
+            }
+        }
+
+        self.update_analysis();
+    }
+
+    fn utility_function(&self) -> Result<UtilityFunction, &'static str> {
+        let parameter = || {
+            self.parameter_input
+                .value
+                .parse::<f32>()
+                .map_err(|_| "Parameter is invalid.")
+        };
+
+        Ok(match self.kind {
+            UtilityFunctionKind::Linear => UtilityFunction::Linear,
+            UtilityFunctionKind::Exponential => UtilityFunction::Exponential(parameter()?),
+            UtilityFunctionKind::Logarithmic => UtilityFunction::Logarithmic(parameter()?),
+            UtilityFunctionKind::Power => UtilityFunction::Power(parameter()?),
+            UtilityFunctionKind::PiecewiseLinear => UtilityFunction::PiecewiseLinear(
+                parse_points(&self.points_input.value).map_err(|_| "Points are invalid.")?,
+            ),
+        })
+    }
+
+    fn update_analysis(&mut self) {
+        match self
+            .utility_function()
+            .and_then(|function| expected_utility(&self.a, &self.p, &function))
+        {
+            Ok(analysis) => {
+                self.analysis = Some(analysis);
+                self.error_text = String::new();
+            }
+            Err(_) => {
+                self.analysis = None;
+                self.error_text = match self.kind {
+                    UtilityFunctionKind::Exponential => {
+                        "Толерантність до ризику R має бути ненульовим числом."
+                    }
+                    UtilityFunctionKind::Logarithmic => {
+                        "Початковий капітал W має бути числом, більшим за модуль найменшого збитку."
+                    }
+                    UtilityFunctionKind::Power => "Показник α має бути додатнім числом.",
+                    _ => {
+                        "Точки мають бути записані як x:u через «;», з x та u, що строго зростають."
+                    }
+                }
+                .to_string();
+            }
+        }
+    }
+
+    pub fn view(&self, alternatives: &[usize]) -> Element<'_, UtilityBlockMessage> {
+        let mut settings = row![pick_list(
+            &UtilityFunctionKind::ALL[..],
+            Some(self.kind),
+            UtilityBlockMessage::KindSelected
+        )]
+        .spacing(10)
+        .align_items(iced::Alignment::Center);
+
+        settings = match self.kind {
+            UtilityFunctionKind::Linear => settings.push(Text::new("u(x) = x")),
+            UtilityFunctionKind::PiecewiseLinear => settings.push(
+                self.points_input
+                    .view()
+                    .map(UtilityBlockMessage::PointsMessage),
+            ),
+            kind => settings
+                .push(Text::new(match kind {
+                    UtilityFunctionKind::Exponential => "u(x) = R(1 − e^(−x/R)), R =",
+                    UtilityFunctionKind::Logarithmic => "u(x) = ln(W + x), W =",
+                    _ => "u(x) = x^α, α =",
+                }))
+                .push(
+                    self.parameter_input
+                        .view()
+                        .map(UtilityBlockMessage::ParameterMessage),
+                ),
+        };
+
+        let mut content =
+            column![Text::new("Очікувана корисність").height(20), settings].spacing(10);
+
+        if let Some(analysis) = &self.analysis {
+            let results: Vec<Vec<f32>> = (0..analysis.expected_utilities.len())
+                .map(|row| {
+                    vec![
+                        analysis.expected_utilities[row],
+                        analysis.certainty_equivalents[row],
+                        analysis.risk_premiums[row],
+                    ]
+                })
+                .collect();
+
+            content = content
+                .push(matrix_block(
+                    "Очікувана корисність; детермінований еквівалент; премія за ризик:",
+                    &results,
+                    alternatives,
+                ))
+                .push(gen_block(
+                    "Найкраща альтернатива",
+                    analysis.best.0,
+                    &analysis.best.1,
+                    alternatives,
+                ));
+        }
+
+        if !self.error_text.is_empty() {
+            content = content.push(Text::new(self.error_text.clone()));
+        }
+
+        content.into()
+    }
+}
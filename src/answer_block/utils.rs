@@ -1,29 +1,105 @@
 use iced::{
-    widget::{column, Text},
+    widget::{column, Column, Text},
     Element,
 };
 
-pub fn generate_variants_block(indeces: &Vec<usize>) -> Text<'static> {
-    return Text::new(format!(
+/// Label of the matrix row `index`. `alternatives` holds original numbers of
+/// the rows, so a matrix with pruned dominated rows keeps the user's numbering.
+pub fn alternative_label(index: usize, alternatives: &[usize]) -> String {
+    format!("Z_{}", alternatives[index] + 1)
+}
+
+/// Numbers of all rows of a matrix with `rows` rows.
+pub fn all_alternatives(rows: usize) -> Vec<usize> {
+    (0..rows).collect()
+}
+
+pub fn format_variants(indeces: &[usize], alternatives: &[usize]) -> String {
+    indeces
+        .iter()
+        .map(|&index| alternative_label(index, alternatives))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn generate_variants_block(indeces: &[usize], alternatives: &[usize]) -> Text<'static> {
+    Text::new(format!(
         "Варіанти: {}",
-        indeces
-            .iter()
-            .map(|index| format!("Z_{}", index + 1))
-            .collect::<Vec<_>>()
-            .join(", ")
-    ));
+        format_variants(indeces, alternatives)
+    ))
 }
 
 pub fn gen_block<T: 'static>(
     title: &'static str,
     answer_value: f32,
-    indeces: &Vec<usize>,
+    indeces: &[usize],
+    alternatives: &[usize],
 ) -> Element<'static, T> {
     column![
         Text::new(title).height(20),
         Text::new(format!("Z = {}", answer_value)),
-        generate_variants_block(indeces)
+        generate_variants_block(indeces, alternatives)
     ]
     .spacing(10)
     .into()
 }
+
+pub fn format_values(values: &[f32]) -> String {
+    values
+        .iter()
+        .map(|value| format!("{value:.4}"))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Matrix printed row by row, used for intermediate steps of methods.
+pub fn matrix_block<T: 'static>(
+    title: &str,
+    a: &[Vec<f32>],
+    alternatives: &[usize],
+) -> Element<'static, T> {
+    let rows = a
+        .iter()
+        .enumerate()
+        .map(|(row, values)| {
+            Text::new(format!(
+                "{}: {}",
+                alternative_label(row, alternatives),
+                format_values(values)
+            ))
+            .into()
+        })
+        .collect();
+
+    column![
+        Text::new(title.to_string()),
+        Column::with_children(rows).spacing(5)
+    ]
+    .spacing(5)
+    .into()
+}
+
+/// Alternatives ordered from the best score to the worst.
+pub fn ranking_block<T: 'static>(
+    scores: &[f32],
+    order: &[usize],
+    alternatives: &[usize],
+) -> Element<'static, T> {
+    Column::with_children(
+        order
+            .iter()
+            .enumerate()
+            .map(|(place, &row)| {
+                Text::new(format!(
+                    "{}. {}: {:.4}",
+                    place + 1,
+                    alternative_label(row, alternatives),
+                    scores[row]
+                ))
+                .into()
+            })
+            .collect(),
+    )
+    .spacing(5)
+    .into()
+}
@@ -1,15 +1,35 @@
+use crate::ahp::comparison::round_weights;
+use crate::ahp::editor::{AhpEditor, AhpEditorMessage};
+use crate::answer_block::dominance::DominanceBlock;
+use crate::answer_block::experiment::{ExperimentBlock, ExperimentBlockMessage};
+use crate::answer_block::fuzzy::{FuzzyBlock, FuzzyBlockMessage};
+use crate::answer_block::game::{GameAnswerBlocks, GameAnswerBlocksMessage};
+use crate::answer_block::interval::{IntervalBlock, IntervalBlockMessage};
+use crate::answer_block::monte_carlo::{MonteCarloBlock, MonteCarloBlockMessage};
+use crate::answer_block::multi_criteria::{MultiCriteriaBlock, MultiCriteriaBlockMessage};
+use crate::answer_block::profits_losses_radio::ProfitsLossesRadioMessage;
 use crate::answer_block::risk_condition::{
     RiskConditionAnswerBlockMessage, RiskConditionAnswerBlocks,
 };
 use crate::answer_block::slider_block::{self};
 use crate::answer_block::uncertainty::{UncertaintyAnswerBlocks, UncertaintyAnswerBlocksMessage};
+use crate::answer_block::utils::all_alternatives;
+use crate::constants::DEFAULT_PROFITS_LOSSES_CHOISE;
+use crate::criterion::dominance::criteria_direction;
+use crate::decision_tree::editor::{TreeEditor, TreeEditorMessage};
+use crate::decision_tree::tree::DecisionTree;
+use crate::fuzzy::Defuzzification;
 use crate::input_panel::{InputPanel, InputPanelMessage};
+use crate::interval::Interval;
+use crate::monte_carlo::Distribution;
 use crate::table::cell::CellMessage;
 use crate::table::table::{InputTable, InputTableMessage};
-use crate::utils::{parse_data, parse_p};
+use crate::utils::{
+    parse_data, parse_distributions, parse_fuzzy_numbers, parse_intervals, parse_p, parse_weights,
+};
 use crate::value_component::ValueInputMessage;
 use iced::widget::scrollable::Properties;
-use iced::widget::{button, column, container, row, scrollable, Text};
+use iced::widget::{button, column, container, pick_list, row, scrollable, Text};
 use iced::{executor, Application, Command, Element, Length, Theme};
 
 pub struct Criteria {
@@ -18,7 +38,22 @@ pub struct Criteria {
     generate_answer: bool,
     uncertainty_answer_block: Option<UncertaintyAnswerBlocks>,
     risk_condition_answer_block: Option<RiskConditionAnswerBlocks>,
+    experiment_block: ExperimentBlock,
+    dominance_block: DominanceBlock,
+    game_answer_block: Option<GameAnswerBlocks>,
+    multi_criteria_block: Option<MultiCriteriaBlock>,
+    monte_carlo_block: Option<MonteCarloBlock>,
+    interval_block: Option<IntervalBlock>,
+    fuzzy_block: Option<FuzzyBlock>,
+    /// Gives the point matrix of fuzzy payoffs and ranks fuzzy scores.
+    defuzzification: Defuzzification,
+    tree_editor: TreeEditor,
+    ahp_editor: AhpEditor,
     answer_generation_error_text: String,
+    /// What the point matrix of the criteria is made of, when cells aren't
+    /// numbers.
+    point_matrix_notice: Option<&'static str>,
+    answer_is_stale: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -28,6 +63,378 @@ pub enum Message {
     GenerateCriterionsButtonPressed,
     UncertaintyAnswerBlock(UncertaintyAnswerBlocksMessage),
     RiskConditionAnswerBlock(RiskConditionAnswerBlockMessage),
+    Experiment(ExperimentBlockMessage),
+    GameAnswerBlock(GameAnswerBlocksMessage),
+    MultiCriteria(MultiCriteriaBlockMessage),
+    MonteCarlo(MonteCarloBlockMessage),
+    Interval(IntervalBlockMessage),
+    Fuzzy(FuzzyBlockMessage),
+    DefuzzificationSelected(Defuzzification),
+    DecisionTree(TreeEditorMessage),
+    Ahp(AhpEditorMessage),
+}
+
+impl Criteria {
+    /// Recomputes answer blocks from the current table.
+    ///
+    /// With `keep_state` existing blocks are refreshed in place (slider and
+    /// radio positions survive) and invalid input only marks them as stale.
+    fn generate_answer_blocks(&mut self, keep_state: bool) {
+        let input_data = self.input_table.get_data();
+        let p = self.input_table.get_p();
+
+        // Besides numbers cells hold either distributions, intervals or fuzzy
+        // numbers. Criteria use expected values, midpoints or defuzzified
+        // values, the spread is explored by the dedicated blocks.
+        let cells = parse_distributions(&input_data).ok();
+        let intervals = parse_intervals(&input_data).ok();
+        let fuzzy_numbers = parse_fuzzy_numbers(&input_data).ok();
+
+        let parsed_input_data: Vec<Vec<f32>> = match (&cells, &intervals, &fuzzy_numbers) {
+            (Some(cells), _, _) => cells
+                .iter()
+                .map(|row| row.iter().map(Distribution::mean).collect())
+                .collect(),
+            (None, Some(intervals), _) => intervals
+                .iter()
+                .map(|row| row.iter().map(Interval::midpoint).collect())
+                .collect(),
+            (None, None, Some(fuzzy_numbers)) => fuzzy_numbers
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|number| number.defuzzify(self.defuzzification))
+                        .collect()
+                })
+                .collect(),
+            (None, None, None) => {
+                self.invalidate_answer_blocks(
+                    keep_state,
+                    "Перевірте заповнену матрицю на коректність.",
+                );
+                return;
+            }
+        };
+        let cells = cells.filter(|cells| cells.iter().flatten().any(|cell| !cell.is_constant()));
+        let intervals = intervals.filter(|intervals| {
+            intervals
+                .iter()
+                .flatten()
+                .any(|interval| !interval.is_point())
+        });
+        let fuzzy_numbers = fuzzy_numbers.filter(|fuzzy_numbers| {
+            fuzzy_numbers
+                .iter()
+                .flatten()
+                .any(|number| !number.is_crisp())
+        });
+
+        // Attributes and games need numbers, the spread has no meaning there.
+        let crisp_data = parse_data(&input_data).ok();
+
+        if self.input_panel.multi_criteria_checked {
+            match crisp_data {
+                Some(a) => self.generate_multi_criteria_block(a, keep_state),
+                None => self.invalidate_answer_blocks(
+                    keep_state,
+                    "Багатокритеріальний аналіз потребує числової матриці.",
+                ),
+            }
+            return;
+        }
+
+        self.multi_criteria_block = None;
+        self.point_matrix_notice = if cells.is_some() {
+            Some("Критерії обраховано на математичних сподіваннях розподілів.")
+        } else if intervals.is_some() {
+            Some("Критерії обраховано на серединах інтервалів.")
+        } else if fuzzy_numbers.is_some() {
+            Some("Критерії обраховано на дефазифікованій матриці.")
+        } else {
+            None
+        };
+
+        let risk_condition = self.input_panel.risk_condition_checked;
+        let with_uncertainty = !risk_condition || self.input_panel.compare_with_uncertainty_checked;
+
+        // Rows are pruned only when all shown criteria agree on the direction.
+        let uncertainty_choise = match &self.uncertainty_answer_block {
+            Some(block) if keep_state => block.profits_losses_radio.get_selected_choise(),
+            _ => DEFAULT_PROFITS_LOSSES_CHOISE,
+        };
+        let dominance_choise = criteria_direction(with_uncertainty, uncertainty_choise);
+
+        self.dominance_block
+            .update_data(&parsed_input_data, dominance_choise);
+        let (parsed_input_data, cells, intervals, fuzzy_numbers, alternatives) =
+            if self.input_panel.prune_dominated_checked {
+                (
+                    self.dominance_block.prune(&parsed_input_data),
+                    cells.map(|cells| self.dominance_block.prune(&cells)),
+                    intervals.map(|intervals| self.dominance_block.prune(&intervals)),
+                    fuzzy_numbers.map(|fuzzy_numbers| self.dominance_block.prune(&fuzzy_numbers)),
+                    self.dominance_block.kept_rows().to_vec(),
+                )
+            } else {
+                let alternatives = all_alternatives(parsed_input_data.len());
+                (
+                    parsed_input_data,
+                    cells,
+                    intervals,
+                    fuzzy_numbers,
+                    alternatives,
+                )
+            };
+
+        let mut error_text = String::new();
+        let mut answer_is_stale = false;
+        let mut valid_p = None;
+
+        if risk_condition {
+            match parse_p(&p) {
+                Ok(parsed_p) => {
+                    valid_p = Some(parsed_p.clone());
+                    self.experiment_block.refresh(&parsed_input_data, &parsed_p);
+
+                    match self.risk_condition_answer_block.as_mut() {
+                        Some(block) if keep_state => block.update_data(
+                            parsed_input_data.clone(),
+                            parsed_p,
+                            alternatives.clone(),
+                        ),
+                        _ => {
+                            self.risk_condition_answer_block = Some(RiskConditionAnswerBlocks::new(
+                                parsed_input_data.clone(),
+                                parsed_p,
+                                alternatives.clone(),
+                            ))
+                        }
+                    }
+                }
+                // Uncertainty criteria don't need `p`, so they are still shown.
+                Err(_) if with_uncertainty => {
+                    if keep_state {
+                        answer_is_stale = self.risk_condition_answer_block.is_some();
+                    } else {
+                        self.risk_condition_answer_block = None;
+                    }
+
+                    error_text = "Перевірте заповнені ймовірності на коректність.".to_string();
+                }
+                Err(_) => {
+                    self.invalidate_answer_blocks(
+                        keep_state,
+                        "Перевірте заповнені ймовірності на коректність.",
+                    );
+                    return;
+                }
+            }
+        } else {
+            self.risk_condition_answer_block = None;
+        }
+
+        // The game is solved on the full matrix, it has its own dominance.
+        if self.input_panel.game_checked {
+            match (crisp_data, self.game_answer_block.as_mut()) {
+                (Some(game_data), Some(block)) if keep_state => block.update_data(game_data),
+                (Some(game_data), _) => {
+                    self.game_answer_block = Some(GameAnswerBlocks::new(game_data))
+                }
+                (None, _) => {
+                    self.game_answer_block = None;
+
+                    if !error_text.is_empty() {
+                        error_text.push('\n');
+                    }
+                    error_text.push_str("Матрична гра потребує числової матриці.");
+                }
+            }
+        } else {
+            self.game_answer_block = None;
+        }
+
+        if with_uncertainty {
+            match self.uncertainty_answer_block.as_mut() {
+                Some(block) if keep_state => {
+                    block.update_data(parsed_input_data, alternatives.clone())
+                }
+                _ => {
+                    self.uncertainty_answer_block = Some(UncertaintyAnswerBlocks::new(
+                        parsed_input_data,
+                        alternatives.clone(),
+                    ))
+                }
+            }
+        } else {
+            self.uncertainty_answer_block = None;
+        }
+
+        match (cells, self.monte_carlo_block.as_mut()) {
+            (Some(cells), Some(block)) if keep_state => block.update_data(
+                cells,
+                valid_p.clone(),
+                uncertainty_choise,
+                alternatives.clone(),
+            ),
+            (Some(cells), _) => {
+                self.monte_carlo_block = Some(MonteCarloBlock::new(
+                    cells,
+                    valid_p.clone(),
+                    uncertainty_choise,
+                    alternatives.clone(),
+                ))
+            }
+            (None, _) => self.monte_carlo_block = None,
+        }
+
+        match (intervals, self.interval_block.as_mut()) {
+            (Some(intervals), Some(block)) if keep_state => block.update_data(
+                intervals,
+                valid_p.clone(),
+                uncertainty_choise,
+                alternatives.clone(),
+            ),
+            (Some(intervals), _) => {
+                self.interval_block = Some(IntervalBlock::new(
+                    intervals,
+                    valid_p.clone(),
+                    uncertainty_choise,
+                    alternatives.clone(),
+                ))
+            }
+            (None, _) => self.interval_block = None,
+        }
+
+        match (fuzzy_numbers, self.fuzzy_block.as_mut()) {
+            (Some(fuzzy_numbers), Some(block)) if keep_state => block.update_data(
+                fuzzy_numbers,
+                valid_p,
+                uncertainty_choise,
+                alternatives,
+                self.defuzzification,
+            ),
+            (Some(fuzzy_numbers), _) => {
+                self.fuzzy_block = Some(FuzzyBlock::new(
+                    fuzzy_numbers,
+                    valid_p,
+                    uncertainty_choise,
+                    alternatives,
+                    self.defuzzification,
+                ))
+            }
+            (None, _) => self.fuzzy_block = None,
+        }
+
+        self.answer_generation_error_text = error_text;
+        self.answer_is_stale = answer_is_stale;
+    }
+
+    /// Multi-criteria mode replaces the other answer blocks, the `p` row
+    /// holds weights of attributes.
+    fn generate_multi_criteria_block(&mut self, a: Vec<Vec<f32>>, keep_state: bool) {
+        self.uncertainty_answer_block = None;
+        self.risk_condition_answer_block = None;
+        self.game_answer_block = None;
+        self.monte_carlo_block = None;
+        self.interval_block = None;
+        self.fuzzy_block = None;
+
+        let Ok(weights) = parse_weights(&self.input_table.get_p()) else {
+            self.invalidate_answer_blocks(keep_state, "Перевірте заповнені ваги на коректність.");
+            return;
+        };
+
+        // Directions of attributes survive regeneration, like other settings.
+        match self.multi_criteria_block.as_mut() {
+            Some(block) => block.update_data(a, weights),
+            None => self.multi_criteria_block = Some(MultiCriteriaBlock::new(a, weights)),
+        }
+
+        self.answer_generation_error_text = String::new();
+        self.answer_is_stale = false;
+    }
+
+    fn sync_p_row(&mut self) {
+        self.input_table.risk_condition = self.input_panel.shows_p_row();
+        self.input_table.p_label = self.input_panel.p_label().to_string();
+    }
+
+    fn invalidate_answer_blocks(&mut self, keep_state: bool, error_text: &str) {
+        if keep_state {
+            self.answer_is_stale = true;
+        } else {
+            self.uncertainty_answer_block = None;
+            self.risk_condition_answer_block = None;
+            self.game_answer_block = None;
+            self.multi_criteria_block = None;
+            self.monte_carlo_block = None;
+            self.interval_block = None;
+            self.fuzzy_block = None;
+        }
+
+        self.answer_generation_error_text = error_text.to_string();
+    }
+
+    /// Converts the input table into a single-stage decision tree. Without
+    /// valid probabilities the states are considered equally likely.
+    fn load_tree_from_matrix(&mut self) {
+        let Ok(a) = parse_data(&self.input_table.get_data()) else {
+            self.tree_editor.error_text = "Перевірте заповнену матрицю на коректність.".to_string();
+            return;
+        };
+
+        let cols = a.first().map_or(0, |row| row.len());
+        let p =
+            parse_p(&self.input_table.get_p()).unwrap_or_else(|_| vec![1.0 / cols as f32; cols]);
+
+        self.tree_editor.load(DecisionTree::from_matrix(&a, &p));
+    }
+
+    /// Writes AHP priorities into the `p` row as probabilities or weights.
+    fn apply_ahp_weights(&mut self) {
+        let Some(result) = &self.ahp_editor.result else {
+            return;
+        };
+
+        if !self.input_panel.shows_p_row()
+            || self.input_table.get_p().len() != result.priorities.len()
+        {
+            self.ahp_editor.error_text =
+                "Кількість пріоритетів має збігатися з кількістю стовпців, а рядок p — бути увімкненим."
+                    .to_string();
+            return;
+        }
+
+        for (index, weight) in round_weights(&result.priorities, 4).iter().enumerate() {
+            self.input_table
+                .update_probability_cell(index, weight.to_string());
+        }
+        self.ahp_editor.error_text = String::new();
+
+        self.on_input_table_changed();
+    }
+
+    fn has_answer_blocks(&self) -> bool {
+        self.uncertainty_answer_block.is_some()
+            || self.risk_condition_answer_block.is_some()
+            || self.game_answer_block.is_some()
+            || self.multi_criteria_block.is_some()
+            || self.monte_carlo_block.is_some()
+            || self.interval_block.is_some()
+            || self.fuzzy_block.is_some()
+    }
+
+    fn on_input_table_changed(&mut self) {
+        if !self.generate_answer {
+            return;
+        }
+
+        if self.input_panel.auto_recompute_checked {
+            self.generate_answer_blocks(true);
+        } else if self.has_answer_blocks() {
+            self.answer_is_stale = true;
+        }
+    }
 }
 
 impl Application for Criteria {
@@ -47,7 +454,19 @@ impl Application for Criteria {
                 generate_answer: false,
                 uncertainty_answer_block: None,
                 risk_condition_answer_block: None,
+                experiment_block: ExperimentBlock::new(),
+                dominance_block: DominanceBlock::new(),
+                game_answer_block: None,
+                multi_criteria_block: None,
+                monte_carlo_block: None,
+                interval_block: None,
+                fuzzy_block: None,
+                defuzzification: Defuzzification::Centroid,
+                tree_editor: TreeEditor::new(),
+                ahp_editor: AhpEditor::new(),
                 answer_generation_error_text: String::new(),
+                point_matrix_notice: None,
+                answer_is_stale: false,
             },
             Command::none(),
         )
@@ -76,8 +495,12 @@ impl Application for Criteria {
                     match self.input_panel.get_x_y() {
                         Ok((x, y)) => {
                             self.input_panel.custom_text = format!("x = {x}, y = {y}");
-                            self.input_table =
-                                InputTable::new(x, y, self.input_panel.risk_condition_checked);
+                            self.input_table = InputTable::new(x, y, false);
+                            self.sync_p_row();
+
+                            if self.has_answer_blocks() {
+                                self.answer_is_stale = true;
+                            }
                         }
                         Err(message) => self.input_panel.custom_text = message.to_string(),
                     }
@@ -85,6 +508,45 @@ impl Application for Criteria {
                 }
                 InputPanelMessage::RiskConditionChecked(risk_condition_checked) => {
                     self.input_panel.risk_condition_checked = risk_condition_checked;
+                    self.sync_p_row();
+                    self.on_input_table_changed();
+
+                    Command::none()
+                }
+                InputPanelMessage::MultiCriteriaChecked(multi_criteria_checked) => {
+                    self.input_panel.multi_criteria_checked = multi_criteria_checked;
+                    self.sync_p_row();
+                    self.on_input_table_changed();
+
+                    Command::none()
+                }
+                InputPanelMessage::CompareWithUncertaintyChecked(
+                    compare_with_uncertainty_checked,
+                ) => {
+                    self.input_panel.compare_with_uncertainty_checked =
+                        compare_with_uncertainty_checked;
+                    self.on_input_table_changed();
+
+                    Command::none()
+                }
+                InputPanelMessage::GameChecked(game_checked) => {
+                    self.input_panel.game_checked = game_checked;
+                    self.on_input_table_changed();
+
+                    Command::none()
+                }
+                InputPanelMessage::PruneDominatedChecked(prune_dominated_checked) => {
+                    self.input_panel.prune_dominated_checked = prune_dominated_checked;
+                    self.on_input_table_changed();
+
+                    Command::none()
+                }
+                InputPanelMessage::AutoRecomputeChecked(auto_recompute_checked) => {
+                    self.input_panel.auto_recompute_checked = auto_recompute_checked;
+
+                    if auto_recompute_checked && self.answer_is_stale {
+                        self.generate_answer_blocks(true);
+                    }
 
                     Command::none()
                 }
@@ -95,6 +557,7 @@ impl Application for Criteria {
                     {
                         ValueInputMessage::ValueChanged(value) => {
                             self.input_table.update_cell(row, col, value);
+                            self.on_input_table_changed();
                             Command::none()
                         }
                     },
@@ -105,6 +568,7 @@ impl Application for Criteria {
                             match input_value_message {
                                 ValueInputMessage::ValueChanged(value) => {
                                     self.input_table.update_probability_cell(col, value);
+                                    self.on_input_table_changed();
                                     Command::none()
                                 }
                             }
@@ -115,38 +579,7 @@ impl Application for Criteria {
             Message::GenerateCriterionsButtonPressed => {
                 self.generate_answer = true;
 
-                let input_data = &self.input_table.get_data();
-                let p = &self.input_table.get_p();
-
-                if let Ok(parsed_input_data) = parse_data(&input_data) {
-                    if self.input_panel.risk_condition_checked {
-                        if let Ok(parsed_p) = parse_p(&p) {
-                            self.uncertainty_answer_block = None;
-                            self.risk_condition_answer_block =
-                                Some(RiskConditionAnswerBlocks::new(parsed_input_data, parsed_p));
-
-                            self.answer_generation_error_text = String::new();
-                        }
-                        else {
-                            self.uncertainty_answer_block = None;
-                            self.risk_condition_answer_block = None;
-                            
-                            self.answer_generation_error_text = "Перевірте заповнені ймовірності на коректність.".to_string();
-                        }
-                    } else {
-                        self.uncertainty_answer_block =
-                        Some(UncertaintyAnswerBlocks::new(parsed_input_data));
-                        self.risk_condition_answer_block = None;
-
-                        self.answer_generation_error_text = String::new();
-                    }
-                }
-                else {
-                    self.uncertainty_answer_block = None;
-                    self.risk_condition_answer_block = None;
-
-                    self.answer_generation_error_text = "Перевірте заповнену матрицю на коректність.".to_string();
-                }
+                self.generate_answer_blocks(false);
 
                 Command::none()
             }
@@ -154,101 +587,337 @@ impl Application for Criteria {
                 UncertaintyAnswerBlocksMessage::Alpha(hurwitz_block_message) => {
                     match hurwitz_block_message {
                         slider_block::SliderBlockMessage::AlphaChange(new_alpha) => {
-                            if self.uncertainty_answer_block.is_some() {
-                                self.uncertainty_answer_block
-                                    .as_mut()
-                                    .unwrap()
-                                    .hurwitz_slider
-                                    .value = new_alpha;
-
-                                self.uncertainty_answer_block
-                                    .as_mut()
-                                    .unwrap()
-                                    .update_hurwitz_block();
+                            if let Some(uncertainty_answer_block) =
+                                self.uncertainty_answer_block.as_mut()
+                            {
+                                uncertainty_answer_block.hurwitz_slider.value = new_alpha;
+                                uncertainty_answer_block.update_hurwitz_block();
                             }
 
                             Command::none()
                         }
                     }
                 }
-                UncertaintyAnswerBlocksMessage::ProfitsLossesRadioChanged(radio_message) => match radio_message {
-                    crate::answer_block::profits_losses_radio::ProfitsLossesRadioMessage::RadioSelected(new_choice) => {
-                        self.uncertainty_answer_block.as_mut().unwrap().profits_losses_radio.update_selected_choise(new_choice);
-                        self.uncertainty_answer_block.as_mut().unwrap().update_minimax();
-                        Command::none()
-                    },
-                },
+                UncertaintyAnswerBlocksMessage::ProfitsLossesRadioChanged(
+                    ProfitsLossesRadioMessage::RadioSelected(new_choice),
+                ) => {
+                    if let Some(uncertainty_answer_block) = self.uncertainty_answer_block.as_mut() {
+                        uncertainty_answer_block
+                            .profits_losses_radio
+                            .update_selected_choise(new_choice);
+                        uncertainty_answer_block.update_minimax();
+                    }
+
+                    // Dominance depends on the direction of minimax.
+                    self.on_input_table_changed();
+
+                    Command::none()
+                }
             },
             Message::RiskConditionAnswerBlock(answer_block_message) => match answer_block_message {
                 RiskConditionAnswerBlockMessage::Alpha(probability_maximization_block_message) => {
                     match probability_maximization_block_message {
                         slider_block::SliderBlockMessage::AlphaChange(new_alpha) => {
-                            if self.risk_condition_answer_block.is_some() {
-                                self.risk_condition_answer_block
-                                    .as_mut()
-                                    .unwrap()
+                            if let Some(risk_condition_answer_block) =
+                                self.risk_condition_answer_block.as_mut()
+                            {
+                                risk_condition_answer_block
                                     .probability_maximization_slider
                                     .value = new_alpha;
-
-                                self.risk_condition_answer_block
-                                    .as_mut()
-                                    .unwrap()
-                                    .update_probability_maximization_block();
+                                risk_condition_answer_block.update_probability_maximization_block();
                             }
 
                             Command::none()
                         }
                     }
                 }
+                RiskConditionAnswerBlockMessage::Utility(utility_block_message) => {
+                    if let Some(risk_condition_answer_block) =
+                        self.risk_condition_answer_block.as_mut()
+                    {
+                        risk_condition_answer_block
+                            .utility_block
+                            .update(utility_block_message);
+                    }
+
+                    Command::none()
+                }
+                RiskConditionAnswerBlockMessage::RiskMeasures(risk_measures_block_message) => {
+                    if let Some(risk_condition_answer_block) =
+                        self.risk_condition_answer_block.as_mut()
+                    {
+                        risk_condition_answer_block
+                            .risk_measures_block
+                            .update(risk_measures_block_message);
+                    }
+
+                    Command::none()
+                }
+                RiskConditionAnswerBlockMessage::DownsideRisk(downside_risk_block_message) => {
+                    if let Some(risk_condition_answer_block) =
+                        self.risk_condition_answer_block.as_mut()
+                    {
+                        risk_condition_answer_block
+                            .downside_risk_block
+                            .update(downside_risk_block_message);
+                    }
+
+                    Command::none()
+                }
+                RiskConditionAnswerBlockMessage::ProspectTheory(prospect_theory_block_message) => {
+                    if let Some(risk_condition_answer_block) =
+                        self.risk_condition_answer_block.as_mut()
+                    {
+                        risk_condition_answer_block
+                            .prospect_theory_block
+                            .update(prospect_theory_block_message);
+                    }
+
+                    Command::none()
+                }
+            },
+            Message::Experiment(experiment_block_message) => match experiment_block_message {
+                ExperimentBlockMessage::SignalsMessage(signals_message) => match signals_message {
+                    ValueInputMessage::ValueChanged(value) => {
+                        self.experiment_block.update_signals_input(value);
+                        Command::none()
+                    }
+                },
+                ExperimentBlockMessage::GenerateButtonPressed => {
+                    if let Some(risk_condition_answer_block) = &self.risk_condition_answer_block {
+                        self.experiment_block
+                            .generate_likelihood_table(risk_condition_answer_block.p.len());
+                    }
+
+                    Command::none()
+                }
+                ExperimentBlockMessage::LikelihoodTable(likelihood_table_message) => {
+                    match likelihood_table_message {
+                        InputTableMessage::CellUpdate(CellMessage::Update(
+                            row,
+                            col,
+                            ValueInputMessage::ValueChanged(value),
+                        )) => {
+                            self.experiment_block
+                                .likelihood_table
+                                .update_cell(row, col, value);
+                        }
+                        InputTableMessage::ProbabilityCellUpdate(_) => {}
+                    }
+
+                    Command::none()
+                }
+                ExperimentBlockMessage::AnalyzeButtonPressed => {
+                    if let Some(risk_condition_answer_block) = &self.risk_condition_answer_block {
+                        self.experiment_block.analyze(
+                            &risk_condition_answer_block.a,
+                            &risk_condition_answer_block.p,
+                        );
+                    }
+
+                    Command::none()
+                }
             },
+            Message::MultiCriteria(multi_criteria_block_message) => {
+                if let Some(multi_criteria_block) = self.multi_criteria_block.as_mut() {
+                    multi_criteria_block.update(multi_criteria_block_message);
+                }
+
+                Command::none()
+            }
+            Message::MonteCarlo(monte_carlo_block_message) => {
+                if let Some(monte_carlo_block) = self.monte_carlo_block.as_mut() {
+                    monte_carlo_block.update(monte_carlo_block_message);
+                }
+
+                Command::none()
+            }
+            Message::Interval(interval_block_message) => {
+                if let Some(interval_block) = self.interval_block.as_mut() {
+                    interval_block.update(interval_block_message);
+                }
+
+                Command::none()
+            }
+            Message::Fuzzy(fuzzy_block_message) => {
+                if let Some(fuzzy_block) = self.fuzzy_block.as_mut() {
+                    fuzzy_block.update(fuzzy_block_message);
+                }
+
+                Command::none()
+            }
+            Message::DefuzzificationSelected(defuzzification) => {
+                // Fuzzy rankings follow the method at once, the point matrix of
+                // the other criteria like any other input.
+                self.defuzzification = defuzzification;
+                if let Some(fuzzy_block) = self.fuzzy_block.as_mut() {
+                    fuzzy_block.set_defuzzification(defuzzification);
+                }
+                self.on_input_table_changed();
+
+                Command::none()
+            }
+            Message::GameAnswerBlock(game_answer_block_message) => {
+                if let Some(game_answer_block) = self.game_answer_block.as_mut() {
+                    game_answer_block.update(game_answer_block_message);
+                }
+
+                Command::none()
+            }
+            Message::DecisionTree(tree_editor_message) => {
+                match tree_editor_message {
+                    TreeEditorMessage::FromMatrixButtonPressed => self.load_tree_from_matrix(),
+                    tree_editor_message => self.tree_editor.update(tree_editor_message),
+                }
+
+                Command::none()
+            }
+            Message::Ahp(ahp_editor_message) => {
+                match ahp_editor_message {
+                    AhpEditorMessage::ApplyWeightsButtonPressed => self.apply_ahp_weights(),
+                    ahp_editor_message => self.ahp_editor.update(ahp_editor_message),
+                }
+
+                Command::none()
+            }
         }
     }
 
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         let mut content = column![
-            row![self
-                .input_panel
-                .view()
-                .map(move |message| Message::InputPanel(message))]
-            .height(Length::Fixed(250.0)),
-            row![self
-                .input_table
-                .view()
-                .map(move |message| Message::InputTable(message))],
+            row![self.input_panel.view().map(Message::InputPanel)].height(Length::Fixed(400.0)),
+            row![self.input_table.view().map(Message::InputTable)],
         ];
 
         if self.input_table.is_non_empty() {
-            content = content.push(
-                button("Визначити критерії").on_press(Message::GenerateCriterionsButtonPressed),
-            )
+            content = content
+                .push(Text::new(
+                    "Клітинка може містити число, розподіл U(a; b), T(a; m; b), N(μ; σ), PERT(a; m; b), інтервал [a; b] або нечітке число (a; b; c), (a; b; c; d). Крім чисел матриця містить клітинки лише одного виду: розподіли, інтервали або нечіткі числа.",
+                ))
+                .push(
+                    button("Визначити критерії")
+                        .on_press(Message::GenerateCriterionsButtonPressed),
+                )
         }
 
         if self.generate_answer {
-            if self.uncertainty_answer_block.is_some() {
-                content = content.push(row![self
-                    .uncertainty_answer_block
-                    .as_ref()
-                    .unwrap()
-                    .view()
-                    .map(move |message| Message::UncertaintyAnswerBlock(message)),])
+            if self.answer_is_stale && self.has_answer_blocks() {
+                content = content.push(row![Text::new(
+                    "Результати неактуальні: вхідні дані змінено після обрахунку."
+                )])
+            }
+
+            if self.uncertainty_answer_block.is_some() || self.risk_condition_answer_block.is_some()
+            {
+                content = content.push(
+                    self.dominance_block
+                        .view(self.input_panel.prune_dominated_checked),
+                );
+
+                if let Some(notice) = self.point_matrix_notice {
+                    content = content.push(row![Text::new(notice)]);
+                }
+            }
+
+            let mut answer_blocks = row![].spacing(80);
+            let with_headers = self.uncertainty_answer_block.is_some()
+                && self.risk_condition_answer_block.is_some();
+
+            if let Some(uncertainty_answer_block) = &self.uncertainty_answer_block {
+                let mut block = column![].spacing(20);
+                if with_headers {
+                    block = block.push(Text::new("В умовах невизначеності").size(24));
+                }
+
+                answer_blocks = answer_blocks.push(
+                    block.push(
+                        uncertainty_answer_block
+                            .view()
+                            .map(Message::UncertaintyAnswerBlock),
+                    ),
+                )
+            }
+
+            if let Some(risk_condition_answer_block) = &self.risk_condition_answer_block {
+                let mut block = column![].spacing(20);
+                if with_headers {
+                    block = block.push(Text::new("В умовах ризику").size(24));
+                }
+
+                answer_blocks = answer_blocks.push(
+                    block
+                        .push(
+                            risk_condition_answer_block
+                                .view()
+                                .map(Message::RiskConditionAnswerBlock),
+                        )
+                        .push(
+                            self.experiment_block
+                                .view(&risk_condition_answer_block.alternatives)
+                                .map(Message::Experiment),
+                        ),
+                )
             }
 
-            if self.risk_condition_answer_block.is_some() {
-                content = content.push(row![self
-                    .risk_condition_answer_block
-                    .as_ref()
-                    .unwrap()
-                    .view()
-                    .map(move |message| Message::RiskConditionAnswerBlock(message))])
+            if let Some(game_answer_block) = &self.game_answer_block {
+                answer_blocks = answer_blocks.push(
+                    column![
+                        Text::new("Матрична гра").size(24),
+                        game_answer_block.view().map(Message::GameAnswerBlock)
+                    ]
+                    .spacing(20),
+                )
+            }
+
+            if let Some(multi_criteria_block) = &self.multi_criteria_block {
+                answer_blocks = answer_blocks.push(
+                    column![
+                        Text::new("Багатокритеріальний аналіз").size(24),
+                        multi_criteria_block.view().map(Message::MultiCriteria)
+                    ]
+                    .spacing(20),
+                )
+            }
+
+            content = content.push(answer_blocks);
+
+            if let Some(monte_carlo_block) = &self.monte_carlo_block {
+                content = content.push(monte_carlo_block.view().map(Message::MonteCarlo));
+            }
+
+            if let Some(interval_block) = &self.interval_block {
+                content = content.push(interval_block.view().map(Message::Interval));
+            }
+
+            if let Some(fuzzy_block) = &self.fuzzy_block {
+                content = content.push(
+                    column![
+                        Text::new("Нечіткі виграші").size(24),
+                        row![
+                            Text::new("Дефазифікація:"),
+                            pick_list(
+                                &Defuzzification::ALL[..],
+                                Some(self.defuzzification),
+                                Message::DefuzzificationSelected
+                            ),
+                        ]
+                        .spacing(10)
+                        .align_items(iced::Alignment::Center),
+                        fuzzy_block.view().map(Message::Fuzzy),
+                    ]
+                    .spacing(10),
+                );
             }
 
             if !self.answer_generation_error_text.is_empty() {
-                content = content.push(row![
-                    Text::new(self.answer_generation_error_text.clone())
-                ])
+                content = content.push(row![Text::new(self.answer_generation_error_text.clone())])
             }
         }
 
+        content = content
+            .push(self.tree_editor.view().map(Message::DecisionTree))
+            .push(self.ahp_editor.view().map(Message::Ahp));
+
         scrollable(container(
             content
                 .width(Length::Fill)
@@ -1,6 +1,38 @@
+use iced::Color;
+
 use crate::answer_block::profits_losses_radio::Choise;
 
 pub const CELL_WIDTH: f32 = 200.0;
 pub const TEXT_CELL_WIDTH: f32 = 50.0;
 pub const DEFAULT_PROFITS_LOSSES_CHOISE: Choise = Choise::Profits;
-pub const RADIO_SIZE: f32 = 15.0;
\ No newline at end of file
+pub const RADIO_SIZE: f32 = 15.0;
+pub const TREE_INDENT: f32 = 30.0;
+pub const TREE_INPUT_WIDTH: f32 = 150.0;
+pub const PARAMETER_INPUT_WIDTH: f32 = 90.0;
+pub const CHART_WIDTH: f32 = 700.0;
+pub const CHART_HEIGHT: f32 = 320.0;
+pub const CHART_MARGIN: f32 = 50.0;
+pub const CHART_LEGEND_WIDTH: f32 = 60.0;
+pub const SIMPLEX_RESOLUTION: usize = 60;
+pub const GRAPH_SIZE: f32 = 360.0;
+pub const GRAPH_NODE_RADIUS: f32 = 18.0;
+pub const BROWN_ROBINSON_TABLE_ROWS: usize = 15;
+pub const BROWN_ROBINSON_MAX_ITERATIONS: usize = 100_000;
+pub const MONTE_CARLO_CHART_POINTS: usize = 200;
+pub const MONTE_CARLO_MAX_SAMPLES: usize = 100_000;
+pub const AHP_MAX_ITERATIONS: usize = 1000;
+pub const AHP_CONSISTENCY_THRESHOLD: f32 = 0.1;
+/// Saaty's random consistency index for matrices of size 1..=15.
+pub const AHP_RANDOM_INDEX: [f32; 15] = [
+    0.0, 0.0, 0.58, 0.9, 1.12, 1.24, 1.32, 1.41, 1.45, 1.49, 1.51, 1.48, 1.56, 1.57, 1.59,
+];
+pub const SERIES_COLORS: [Color; 8] = [
+    Color::from_rgb(0.12, 0.47, 0.71),
+    Color::from_rgb(1.0, 0.5, 0.05),
+    Color::from_rgb(0.17, 0.63, 0.17),
+    Color::from_rgb(0.84, 0.15, 0.16),
+    Color::from_rgb(0.58, 0.4, 0.74),
+    Color::from_rgb(0.55, 0.34, 0.29),
+    Color::from_rgb(0.89, 0.47, 0.76),
+    Color::from_rgb(0.5, 0.5, 0.5),
+];
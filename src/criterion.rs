@@ -1,115 +1,276 @@
-pub fn get_max(v: &Vec<f32>) -> f32 {
+pub fn get_max(v: &[f32]) -> f32 {
     *(v.iter().max_by(|x, y| x.partial_cmp(y).unwrap()).unwrap())
 }
 
-pub fn get_min(v: &Vec<f32>) -> f32 {
+pub fn get_min(v: &[f32]) -> f32 {
     *(v.iter().min_by(|x, y| x.partial_cmp(y).unwrap()).unwrap())
 }
 
-fn get_indeces(a: &Vec<f32>, value: f32) -> Vec<usize> {
+pub fn get_indeces(a: &[f32], value: f32) -> Vec<usize> {
     (0..a.len()).filter(|index| a[*index] == value).collect()
 }
 
-pub mod uncertainty {
-    use crate::answer_block::profits_losses_radio::Choise;
+fn get_indeces_by(a: &[f32], predicate: impl Fn(f32) -> bool) -> Vec<usize> {
+    (0..a.len()).filter(|index| predicate(a[*index])).collect()
+}
 
-    use super::{get_indeces, get_max, get_min};
+/// Payoff of a cell: a number, an interval or a fuzzy number. Scores of
+/// criteria are built from these operations, so every kind of payoff shares
+/// one implementation of them.
+pub trait Payoff: Copy {
+    fn point(value: f32) -> Self;
 
-    pub fn maximax(a: &Vec<Vec<f32>>) -> (f32, Vec<usize>) {
-        let z: Vec<_> = a.iter().map(|row| get_max(row)).collect();
+    fn min(self, other: Self) -> Self;
 
-        let answer = get_max(&z);
+    fn max(self, other: Self) -> Self;
+
+    fn add(self, other: Self) -> Self;
 
-        (answer, get_indeces(&z, answer))
+    fn sub(self, other: Self) -> Self;
+
+    /// Product with a non-negative number.
+    fn scale(self, k: f32) -> Self;
+}
+
+impl Payoff for f32 {
+    fn point(value: f32) -> Self {
+        value
     }
 
-    pub fn minimax(a: &Vec<Vec<f32>>, profits_losses: Choise) -> (f32, Vec<usize>) {
-        if profits_losses == Choise::Profits {
-            let z: Vec<_> = a.iter().map(|row| get_min(row)).collect();
+    fn min(self, other: Self) -> Self {
+        f32::min(self, other)
+    }
 
-            let answer = get_max(&z);
+    fn max(self, other: Self) -> Self {
+        f32::max(self, other)
+    }
 
-            (answer, get_indeces(&z, answer))
-        } else {
-            let z: Vec<_> = a.iter().map(|row| get_max(row)).collect();
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
 
-            let answer = get_min(&z);
+    fn sub(self, other: Self) -> Self {
+        self - other
+    }
 
-            (answer, get_indeces(&z, answer))
-        }
+    fn scale(self, k: f32) -> Self {
+        k * self
     }
+}
 
-    pub fn hurwitz(a: &Vec<Vec<f32>>, alpha: f32) -> (f32, Vec<usize>) {
-        let z: Vec<_> = a
-            .iter()
-            .map(|row| {
-                let min_a = get_min(row);
-                let max_a = get_max(row);
+pub fn row_min<T: Payoff>(row: &[T]) -> T {
+    row.iter().copied().reduce(T::min).unwrap()
+}
 
-                return alpha * max_a + (1.0 - alpha) * min_a;
-            })
+pub fn row_max<T: Payoff>(row: &[T]) -> T {
+    row.iter().copied().reduce(T::max).unwrap()
+}
+
+/// Best score and alternatives reaching it.
+pub fn get_best(z: &[f32], minimize: bool) -> (f32, Vec<usize>) {
+    let answer = if minimize { get_min(z) } else { get_max(z) };
+
+    (answer, get_indeces(z, answer))
+}
+
+/// Upper envelope of lines `intercept + x * slope` over `x ∈ [0, 1]`.
+///
+/// Breakpoints are found analytically as intersections of the envelope with
+/// steeper lines. Returns pairs of interval start and indeces of the lines
+/// forming the envelope on that interval.
+pub fn upper_envelope(lines: &[(f32, f32)]) -> Vec<(f32, Vec<usize>)> {
+    let value_at = |(intercept, slope): (f32, f32), x: f32| intercept + x * slope;
+    let is_close = |x: f32, y: f32| (x - y).abs() <= 1e-5 * x.abs().max(y.abs()).max(1.0);
+
+    let mut switching_points = Vec::new();
+    let mut x = 0.0;
+
+    loop {
+        let values: Vec<f32> = lines.iter().map(|line| value_at(*line, x)).collect();
+        let best_value = get_max(&values);
+        let candidates = get_indeces_by(&values, |value| is_close(value, best_value));
+
+        // The line with the steepest slope stays on the envelope right of `x`.
+        let best_slope = get_max(&candidates.iter().map(|&i| lines[i].1).collect::<Vec<_>>());
+        let optimal: Vec<usize> = candidates
+            .into_iter()
+            .filter(|&i| is_close(lines[i].1, best_slope))
             .collect();
 
-        let answer = get_max(&z);
+        let (intercept, slope) = lines[optimal[0]];
+        switching_points.push((x, optimal));
 
-        (answer, get_indeces(&z, answer))
+        let next_x = lines
+            .iter()
+            .filter(|(_, other_slope)| *other_slope > slope && !is_close(*other_slope, slope))
+            .map(|(other_intercept, other_slope)| {
+                (intercept - other_intercept) / (other_slope - slope)
+            })
+            .filter(|&crossing| crossing > x && !is_close(crossing, x) && crossing <= 1.0)
+            .min_by(|a, b| a.partial_cmp(b).unwrap());
+
+        match next_x {
+            Some(next_x) => x = next_x,
+            None => break,
+        }
     }
 
-    pub fn savage(a: &Vec<Vec<f32>>) -> (f32, Vec<usize>) {
-        let rows = a.len();
+    switching_points
+}
 
-        let cols = if rows > 0 { a[0].len() } else { 0 };
+pub mod uncertainty {
+    use crate::answer_block::profits_losses_radio::Choise;
 
-        let ys: Vec<_> = (0..cols)
-            .map(|col_index| {
-                let y = (0..rows).map(|row_index| a[row_index][col_index]).collect();
+    use super::{get_best, get_max, get_min, row_max, row_min, upper_envelope, Payoff};
 
-                get_max(&y)
+    pub fn maximax_scores<T: Payoff>(a: &[Vec<T>]) -> Vec<T> {
+        a.iter().map(|row| row_max(row)).collect()
+    }
+
+    pub fn maximax(a: &[Vec<f32>]) -> (f32, Vec<usize>) {
+        get_best(&maximax_scores(a), false)
+    }
+
+    /// Guaranteed result of every alternative: the smallest profit or the
+    /// largest loss. Losses are minimized.
+    pub fn minimax_scores<T: Payoff>(a: &[Vec<T>], profits_losses: Choise) -> Vec<T> {
+        match profits_losses {
+            Choise::Profits => a.iter().map(|row| row_min(row)).collect(),
+            Choise::Losses => a.iter().map(|row| row_max(row)).collect(),
+        }
+    }
+
+    pub fn minimax(a: &[Vec<f32>], profits_losses: Choise) -> (f32, Vec<usize>) {
+        get_best(
+            &minimax_scores(a, profits_losses),
+            profits_losses == Choise::Losses,
+        )
+    }
+
+    pub fn hurwitz_scores<T: Payoff>(a: &[Vec<T>], alpha: f32) -> Vec<T> {
+        a.iter()
+            .map(|row| {
+                row_max(row)
+                    .scale(alpha)
+                    .add(row_min(row).scale(1.0 - alpha))
             })
-            .collect();
+            .collect()
+    }
 
-        let savage_matrix: Vec<_> = a
+    pub fn hurwitz(a: &[Vec<f32>], alpha: f32) -> (f32, Vec<usize>) {
+        get_best(&hurwitz_scores(a, alpha), false)
+    }
+
+    /// Splits `alpha ∈ [0, 1]` into intervals with the same Hurwitz-optimal
+    /// alternatives. Every alternative is a line `min + alpha * (max - min)`.
+    pub fn hurwitz_switching_points(a: &[Vec<f32>]) -> Vec<(f32, Vec<usize>)> {
+        let lines: Vec<(f32, f32)> = a
             .iter()
             .map(|row| {
-                return row
+                let min_a = get_min(row);
+                let max_a = get_max(row);
+
+                (min_a, max_a - min_a)
+            })
+            .collect();
+
+        upper_envelope(&lines)
+    }
+
+    /// Maximal regrets `max_k a_kj - a_ij`. The regret is taken against the
+    /// other alternatives and cut at zero, which is the same for numbers, but
+    /// keeps bounds of interval and fuzzy regrets tight.
+    pub fn savage_scores<T: Payoff>(a: &[Vec<T>]) -> Vec<T> {
+        a.iter()
+            .enumerate()
+            .map(|(row, values)| {
+                let regrets: Vec<T> = values
                     .iter()
                     .enumerate()
-                    .map(|(index, element)| ys[index] - element)
+                    .map(|(col, &value)| {
+                        a.iter()
+                            .enumerate()
+                            .filter(|&(other, _)| other != row)
+                            .map(|(_, other)| other[col])
+                            .reduce(T::max)
+                            .map_or(T::point(0.0), |others| others.sub(value).max(T::point(0.0)))
+                    })
                     .collect();
-            })
-            .collect();
 
-        let z = savage_matrix.iter().map(|row| get_max(row)).collect();
-        let answer = get_min(&z);
+                row_max(&regrets)
+            })
+            .collect()
+    }
 
-        (answer, get_indeces(&z, answer))
+    pub fn savage(a: &[Vec<f32>]) -> (f32, Vec<usize>) {
+        get_best(&savage_scores(a), true)
     }
 }
 
 pub mod risk_condition {
-    use super::{get_indeces, get_max, get_min};
+    use super::{get_best, get_indeces, get_max, upper_envelope, Payoff};
 
-    pub fn bayes(a: &Vec<Vec<f32>>, p: &Vec<f32>) -> (f32, Vec<usize>) {
-        let z = a
-            .iter()
+    pub fn bayes_scores<T: Payoff>(a: &[Vec<T>], p: &[f32]) -> Vec<T> {
+        a.iter()
             .map(|row| {
                 row.iter()
                     .zip(p.iter())
-                    .map(|(value, probability)| value * probability)
-                    .sum()
+                    .fold(T::point(0.0), |sum, (value, probability)| {
+                        sum.add(value.scale(*probability))
+                    })
             })
-            .collect();
+            .collect()
+    }
 
-        let answer = get_max(&z);
+    pub fn bayes(a: &[Vec<f32>], p: &[f32]) -> (f32, Vec<usize>) {
+        get_best(&bayes_scores(a, p), false)
+    }
+
+    /// Expected value with perfect information: for every state the best
+    /// alternative is known in advance.
+    pub fn expected_value_under_certainty(a: &[Vec<f32>], p: &[f32]) -> f32 {
+        let cols = if !a.is_empty() { a[0].len() } else { 0 };
 
-        (answer, get_indeces(&z, answer))
+        (0..cols)
+            .map(|col_index| {
+                let y: Vec<f32> = a.iter().map(|row| row[col_index]).collect();
+
+                get_max(&y) * p[col_index]
+            })
+            .sum()
     }
 
-    pub fn dispersion_minimization(a: &Vec<Vec<f32>>, p: &Vec<f32>) -> (f32, Vec<usize>) {
-        let z_squared = a
-            .iter()
+    /// Expected opportunity loss (expected regret) of every alternative.
+    pub fn expected_opportunity_losses(a: &[Vec<f32>], p: &[f32]) -> Vec<f32> {
+        let cols = if !a.is_empty() { a[0].len() } else { 0 };
+
+        let ys: Vec<f32> = (0..cols)
+            .map(|col_index| get_max(&a.iter().map(|row| row[col_index]).collect::<Vec<_>>()))
+            .collect();
+
+        a.iter()
             .map(|row| {
                 row.iter()
+                    .zip(ys.iter())
+                    .zip(p.iter())
+                    .map(|((value, y), probability)| (y - value) * probability)
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Expected value of perfect information. Equals the minimal expected
+    /// opportunity loss, which is reached on the Bayes-optimal alternatives.
+    pub fn evpi(a: &[Vec<f32>], p: &[f32]) -> (f32, Vec<usize>) {
+        get_best(&expected_opportunity_losses(a, p), true)
+    }
+
+    /// Standard deviation of outcomes of every alternative.
+    pub fn dispersion_scores(a: &[Vec<f32>], p: &[f32]) -> Vec<f32> {
+        a.iter()
+            .map(|row| {
+                let variance = row
+                    .iter()
                     .zip(p.iter())
                     .map(|(value, probability)| value.powi(2) * probability)
                     .sum::<f32>()
@@ -118,30 +279,26 @@ pub mod risk_condition {
                         .zip(p.iter())
                         .map(|(value, probability)| value * probability)
                         .sum::<f32>()
-                        .powi(2)
-            })
-            .collect();
+                        .powi(2);
 
-        let answer_squared = get_min(&z_squared);
+                // Rounding may leave a constant row slightly below zero.
+                variance.max(0.0).sqrt()
+            })
+            .collect()
+    }
 
-        (
-            answer_squared.sqrt(),
-            get_indeces(&z_squared, answer_squared),
-        )
+    pub fn dispersion_minimization(a: &[Vec<f32>], p: &[f32]) -> (f32, Vec<usize>) {
+        get_best(&dispersion_scores(a, p), true)
     }
 
     pub fn probability_maximization(
-        a: &Vec<Vec<f32>>,
-        p: &Vec<f32>,
+        a: &[Vec<f32>],
+        p: &[f32],
         b: Option<f32>,
     ) -> Option<(f32, Vec<usize>)> {
-        if b.is_none() {
-            return None;
-        }
-
-        let b = b.unwrap();
+        let b = b?;
 
-        let z = a
+        let z: Vec<f32> = a
             .iter()
             .map(|row| {
                 row.iter()
@@ -156,26 +313,283 @@ pub mod risk_condition {
         Some((answer, get_indeces(&z, answer)))
     }
 
-    pub fn modal(a: &Vec<Vec<f32>>, p: &Vec<f32>) -> Option<(f32, Vec<usize>)> {
-        let max_probability = get_max(&p);
+    /// Range of `p_j` for every state `j` over which the current Bayes-optimal
+    /// alternative stays optimal. Other probabilities are rescaled
+    /// proportionally so that they still sum up to one.
+    pub fn bayes_probability_ranges(a: &[Vec<f32>], p: &[f32]) -> Vec<(f32, f32)> {
+        let best = bayes(a, p).1[0];
+
+        (0..p.len())
+            .map(|j| {
+                let rest = 1.0 - p[j];
+                let others = p.len() - 1;
+
+                // Expected value as a line `intercept + p_j * slope`.
+                let line = |row: &Vec<f32>| {
+                    let other_expectation = (0..p.len())
+                        .filter(|&k| k != j)
+                        .map(|k| {
+                            if rest > f32::EPSILON {
+                                row[k] * p[k] / rest
+                            } else {
+                                row[k] / others as f32
+                            }
+                        })
+                        .sum::<f32>();
+
+                    (other_expectation, row[j] - other_expectation)
+                };
+
+                let (best_intercept, best_slope) = line(&a[best]);
+                let (mut low, mut high) = (0.0f32, 1.0f32);
+
+                for (index, row) in a.iter().enumerate() {
+                    if index == best {
+                        continue;
+                    }
+
+                    let (intercept, slope) = line(row);
+                    let slope_difference = best_slope - slope;
+                    let intercept_difference = intercept - best_intercept;
+
+                    if slope_difference > 0.0 {
+                        low = low.max(intercept_difference / slope_difference);
+                    } else if slope_difference < 0.0 {
+                        high = high.min(intercept_difference / slope_difference);
+                    } else if intercept_difference > 0.0 {
+                        (low, high) = (p[j], p[j]);
+                    }
+                }
+
+                (low.clamp(0.0, 1.0), high.clamp(0.0, 1.0))
+            })
+            .collect()
+    }
+
+    /// Intervals of `p_1` with the same Bayes-optimal alternatives for a
+    /// two-state problem where `p_2 = 1 - p_1`.
+    pub fn bayes_switching_points(a: &[Vec<f32>]) -> Vec<(f32, Vec<usize>)> {
+        let lines: Vec<(f32, f32)> = a.iter().map(|row| (row[1], row[0] - row[1])).collect();
+
+        upper_envelope(&lines)
+    }
+
+    /// Distinct matrix values in ascending order. `P(outcome > b)` of every
+    /// alternative changes only when `b` crosses one of them.
+    pub fn probability_maximization_breakpoints(a: &[Vec<f32>]) -> Vec<f32> {
+        let mut values: Vec<f32> = a.iter().flatten().copied().collect();
+
+        values.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        values.dedup();
+
+        values
+    }
+
+    /// `P(outcome > b)` of every alternative for each `b` from `thresholds`.
+    pub fn exceedance_probabilities(
+        a: &[Vec<f32>],
+        p: &[f32],
+        thresholds: &[f32],
+    ) -> Vec<Vec<f32>> {
+        a.iter()
+            .map(|row| {
+                thresholds
+                    .iter()
+                    .map(|b| {
+                        row.iter()
+                            .zip(p.iter())
+                            .filter(|(value, _)| *value > b)
+                            .map(|(_, probability)| probability)
+                            .sum()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The most probable state, `None` when there are several of them.
+    pub fn modal_state(p: &[f32]) -> Option<usize> {
+        let max_probability = get_max(p);
 
         if p.iter().filter(|&value| *value == max_probability).count() > 1 {
             return None;
         }
 
-        let max_probability_element_index = p
+        p.iter().position(|&value| value == max_probability)
+    }
+
+    /// Outcomes in the most probable state.
+    pub fn modal_scores<T: Payoff>(a: &[Vec<T>], p: &[f32]) -> Option<Vec<T>> {
+        let state = modal_state(p)?;
+
+        Some(a.iter().map(|row| row[state]).collect())
+    }
+
+    pub fn modal(a: &[Vec<f32>], p: &[f32]) -> Option<(f32, Vec<usize>)> {
+        modal_scores(a, p).map(|z| get_best(&z, false))
+    }
+}
+
+pub mod experiment {
+    use super::risk_condition::{bayes, evpi};
+
+    pub struct ExperimentAnalysis {
+        /// `P(signal)` for every signal.
+        pub signal_probabilities: Vec<f32>,
+        /// Posterior probabilities of states for every signal.
+        pub posteriors: Vec<Vec<f32>>,
+        /// Bayes decision for every signal, `None` for impossible signals.
+        pub decisions: Vec<Option<(f32, Vec<usize>)>>,
+        pub expected_value_with_sample_information: f32,
+        pub evsi: f32,
+        /// EVSI relative to EVPI, `None` when perfect information is worthless.
+        pub efficiency: Option<f32>,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum ExperimentError {
+        /// Likelihood matrix is empty or its columns don`t match states.
+        SizeMismatch,
+        LikelihoodOutOfRange,
+        /// Likelihoods of the state with this index don`t sum up to 1.
+        UnnormalizedState(usize),
+    }
+
+    /// Updates prior `p` with `likelihoods[signal][state] = P(signal | state)`
+    /// and evaluates the expected value of sample information.
+    pub fn analyze(
+        a: &[Vec<f32>],
+        p: &[f32],
+        likelihoods: &[Vec<f32>],
+    ) -> Result<ExperimentAnalysis, ExperimentError> {
+        if likelihoods.is_empty() || likelihoods.iter().any(|row| row.len() != p.len()) {
+            return Err(ExperimentError::SizeMismatch);
+        }
+
+        if likelihoods
             .iter()
-            .position(|&value| value == max_probability)
-            .unwrap();
+            .flatten()
+            .any(|likelihood| !(0.0..=1.0).contains(likelihood))
+        {
+            return Err(ExperimentError::LikelihoodOutOfRange);
+        }
+
+        if let Some(state) = (0..p.len()).find(|&state| {
+            (likelihoods.iter().map(|row| row[state]).sum::<f32>() - 1.0).abs() > 1e-4
+        }) {
+            return Err(ExperimentError::UnnormalizedState(state));
+        }
 
-        let z = a
+        let signal_probabilities: Vec<f32> = likelihoods
             .iter()
-            .map(|row| row[max_probability_element_index])
+            .map(|row| row.iter().zip(p.iter()).map(|(l, p)| l * p).sum())
             .collect();
 
-        let answer = get_max(&z);
+        let posteriors: Vec<Vec<f32>> = likelihoods
+            .iter()
+            .zip(signal_probabilities.iter())
+            .map(|(row, &signal_probability)| {
+                if signal_probability > 0.0 {
+                    row.iter()
+                        .zip(p.iter())
+                        .map(|(l, p)| l * p / signal_probability)
+                        .collect()
+                } else {
+                    p.to_vec()
+                }
+            })
+            .collect();
 
-        Some((answer, get_indeces(&z, answer)))
+        let decisions: Vec<Option<(f32, Vec<usize>)>> = posteriors
+            .iter()
+            .zip(signal_probabilities.iter())
+            .map(|(posterior, &signal_probability)| {
+                (signal_probability > 0.0).then(|| bayes(a, posterior))
+            })
+            .collect();
+
+        let expected_value_with_sample_information = decisions
+            .iter()
+            .zip(signal_probabilities.iter())
+            .filter_map(|(decision, signal_probability)| {
+                decision
+                    .as_ref()
+                    .map(|(value, _)| value * signal_probability)
+            })
+            .sum::<f32>();
+
+        let evsi = expected_value_with_sample_information - bayes(a, p).0;
+        let evpi = evpi(a, p).0;
+
+        Ok(ExperimentAnalysis {
+            signal_probabilities,
+            posteriors,
+            decisions,
+            expected_value_with_sample_information,
+            evsi,
+            efficiency: (evpi > 0.0).then_some(evsi / evpi),
+        })
+    }
+}
+
+pub mod dominance {
+    use crate::answer_block::profits_losses_radio::Choise;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Dominance {
+        /// Better in every state.
+        Strict,
+        /// Not worse in any state and better in at least one.
+        Weak,
+    }
+
+    /// Checks whether alternative `x` dominates alternative `y`.
+    pub fn dominates(x: &[f32], y: &[f32], choise: Choise) -> Option<Dominance> {
+        let better = |x: f32, y: f32| match choise {
+            Choise::Profits => x > y,
+            Choise::Losses => x < y,
+        };
+
+        if x.iter().zip(y.iter()).all(|(&x, &y)| better(x, y)) {
+            Some(Dominance::Strict)
+        } else if x
+            .iter()
+            .zip(y.iter())
+            .all(|(&x, &y)| x == y || better(x, y))
+            && x != y
+        {
+            Some(Dominance::Weak)
+        } else {
+            None
+        }
+    }
+
+    /// All pairs `(dominating, dominated, kind)` of alternatives.
+    pub fn dominance_relations(a: &[Vec<f32>], choise: Choise) -> Vec<(usize, usize, Dominance)> {
+        (0..a.len())
+            .flat_map(|x| (0..a.len()).map(move |y| (x, y)))
+            .filter_map(|(x, y)| dominates(&a[x], &a[y], choise).map(|kind| (x, y, kind)))
+            .collect()
+    }
+
+    /// Direction shared by all shown criteria, `None` when they disagree.
+    /// Only minimax may treat payoffs as losses, maximax, Hurwitz, Savage and
+    /// the criteria under risk always maximize profits.
+    pub fn criteria_direction(with_uncertainty: bool, minimax_choise: Choise) -> Option<Choise> {
+        match (with_uncertainty, minimax_choise) {
+            (true, Choise::Losses) => None,
+            _ => Some(Choise::Profits),
+        }
+    }
+
+    /// Alternatives not dominated by any other one. Dominance is transitive,
+    /// so a single pass over the relations is enough.
+    pub fn non_dominated(a: &[Vec<f32>], choise: Choise) -> Vec<usize> {
+        let relations = dominance_relations(a, choise);
+
+        (0..a.len())
+            .filter(|row| !relations.iter().any(|(_, dominated, _)| dominated == row))
+            .collect()
     }
 }
 
@@ -184,7 +598,7 @@ mod tests {
     mod uncertainty {
         use crate::{
             answer_block::profits_losses_radio::Choise,
-            criterion::uncertainty::{hurwitz, maximax, minimax, savage},
+            criterion::uncertainty::{hurwitz, hurwitz_switching_points, maximax, minimax, savage},
         };
 
         fn generate_test_data() -> Vec<Vec<f32>> {
@@ -220,6 +634,21 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_hurwitz_switching_points() {
+            let a = generate_test_data();
+
+            let switching_points = hurwitz_switching_points(&a);
+
+            assert_eq!(switching_points.len(), 2, "Expected one switching point.");
+            assert_eq!(switching_points[0], (0.0, vec![0]));
+            assert!(
+                (switching_points[1].0 - 1.0 / 3.0).abs() < 1e-6,
+                "Hurwitz switches at incorrect alpha."
+            );
+            assert_eq!(switching_points[1].1, vec![1]);
+        }
+
         #[test]
         fn test_savage() {
             let a = generate_test_data();
@@ -230,7 +659,9 @@ mod tests {
 
     mod risk_condition {
         use crate::criterion::risk_condition::{
-            bayes, dispersion_minimization, modal, probability_maximization,
+            bayes, bayes_probability_ranges, bayes_switching_points, dispersion_minimization, evpi,
+            exceedance_probabilities, expected_opportunity_losses, expected_value_under_certainty,
+            modal, probability_maximization, probability_maximization_breakpoints,
         };
 
         fn generate_test_data() -> (Vec<Vec<f32>>, Vec<f32>) {
@@ -253,6 +684,70 @@ mod tests {
             assert_eq!(bayes(&a, &p).0, 25_000.0, "Bayes gives incorrect result.")
         }
 
+        #[test]
+        fn test_bayes_probability_ranges() {
+            let (a, p) = generate_test_data();
+
+            let ranges = bayes_probability_ranges(&a, &p);
+
+            assert!(
+                (ranges[0].0 - 4.0 / 9.0).abs() < 1e-5 && ranges[0].1 == 1.0,
+                "Bayes stability range for p_1 is incorrect: {:?}",
+                ranges[0]
+            );
+            assert!(
+                ranges
+                    .iter()
+                    .zip(p.iter())
+                    .all(|((low, high), probability)| low <= probability && probability <= high),
+                "Current probabilities must lie inside stability ranges."
+            );
+        }
+
+        #[test]
+        fn test_bayes_switching_points() {
+            let a = vec![vec![10.0, 0.0], vec![0.0, 10.0], vec![6.0, 6.0]];
+
+            let switching_points = bayes_switching_points(&a);
+
+            assert_eq!(
+                switching_points
+                    .iter()
+                    .map(|(_, indeces)| indeces.clone())
+                    .collect::<Vec<_>>(),
+                vec![vec![1], vec![2], vec![0]],
+                "Bayes optimal alternatives for two states are incorrect."
+            );
+            assert!((switching_points[1].0 - 0.4).abs() < 1e-5);
+            assert!((switching_points[2].0 - 0.6).abs() < 1e-5);
+        }
+
+        #[test]
+        fn test_evpi() {
+            let (a, p) = generate_test_data();
+
+            assert_eq!(
+                expected_value_under_certainty(&a, &p),
+                91_500.0,
+                "Expected value under certainty is incorrect."
+            );
+
+            assert_eq!(
+                expected_opportunity_losses(&a, &p),
+                vec![66_500.0, 81_500.0, 82_500.0, 91_500.0],
+                "Expected opportunity losses are incorrect."
+            );
+
+            assert_eq!(
+                evpi(&a, &p),
+                (
+                    expected_value_under_certainty(&a, &p) - bayes(&a, &p).0,
+                    bayes(&a, &p).1
+                ),
+                "EVPI should equal EVuC minus the Bayes value."
+            );
+        }
+
         #[test]
         fn test_dispersion_minimization() {
             let (a, p) = generate_test_data();
@@ -281,6 +776,25 @@ mod tests {
             )
         }
 
+        #[test]
+        fn test_probability_maximization_breakpoints() {
+            let (a, p) = generate_test_data();
+
+            let breakpoints = probability_maximization_breakpoints(&a);
+
+            assert_eq!(
+                breakpoints,
+                vec![-50_000.0, 0.0, 15_000.0, 100_000.0],
+                "Breakpoints should be distinct sorted matrix values."
+            );
+
+            assert_eq!(
+                exceedance_probabilities(&a, &p, &breakpoints)[0],
+                vec![0.5, 0.5, 0.5, 0.0],
+                "Exceedance probabilities are incorrect for the first alternative."
+            );
+        }
+
         #[test]
         fn test_modal() {
             let (a, p) = generate_test_data();
@@ -292,4 +806,180 @@ mod tests {
             )
         }
     }
+
+    mod experiment {
+        use crate::criterion::experiment::{analyze, ExperimentError};
+
+        #[test]
+        fn test_analyze() {
+            let a = vec![vec![100.0, -100.0], vec![0.0, 0.0]];
+            let p = vec![0.5, 0.5];
+            let likelihoods = vec![vec![0.8, 0.3], vec![0.2, 0.7]];
+
+            let analysis = analyze(&a, &p, &likelihoods).unwrap();
+
+            assert!((analysis.signal_probabilities[0] - 0.55).abs() < 1e-5);
+            assert!((analysis.posteriors[1][0] - 0.1 / 0.45).abs() < 1e-5);
+            assert_eq!(analysis.decisions[0].as_ref().unwrap().1, vec![0]);
+            assert_eq!(analysis.decisions[1].as_ref().unwrap().1, vec![1]);
+            assert!(
+                (analysis.evsi - 25.0).abs() < 1e-3,
+                "EVSI is incorrect: {}",
+                analysis.evsi
+            );
+            assert!((analysis.efficiency.unwrap() - 0.5).abs() < 1e-5);
+        }
+
+        #[test]
+        fn test_analyze_invalid_likelihoods() {
+            let a = vec![vec![100.0, -100.0], vec![0.0, 0.0]];
+            let p = vec![0.5, 0.5];
+            let likelihoods = vec![vec![0.8, 0.3], vec![0.3, 0.7]];
+
+            assert_eq!(
+                analyze(&a, &p, &likelihoods).err(),
+                Some(ExperimentError::UnnormalizedState(0)),
+                "Likelihoods of the first state don`t sum up to 1."
+            );
+            assert_eq!(
+                analyze(&a, &p, &[vec![0.8, 0.3, 0.0]]).err(),
+                Some(ExperimentError::SizeMismatch)
+            );
+            assert_eq!(
+                analyze(&a, &p, &[vec![1.2, 0.3], vec![-0.2, 0.7]]).err(),
+                Some(ExperimentError::LikelihoodOutOfRange)
+            );
+        }
+    }
+
+    mod dominance {
+        use crate::{
+            answer_block::profits_losses_radio::Choise,
+            criterion::{
+                dominance::{
+                    criteria_direction, dominance_relations, dominates, non_dominated, Dominance,
+                },
+                uncertainty::maximax,
+            },
+        };
+
+        fn generate_test_data() -> Vec<Vec<f32>> {
+            vec![
+                vec![5.0, 7.0, 3.0],
+                vec![4.0, 6.0, 2.0],
+                vec![5.0, 6.0, 3.0],
+                vec![8.0, 1.0, 2.0],
+            ]
+        }
+
+        #[test]
+        fn test_dominates() {
+            let a = generate_test_data();
+
+            assert_eq!(
+                dominates(&a[0], &a[1], Choise::Profits),
+                Some(Dominance::Strict)
+            );
+            assert_eq!(
+                dominates(&a[0], &a[2], Choise::Profits),
+                Some(Dominance::Weak)
+            );
+            assert_eq!(dominates(&a[0], &a[3], Choise::Profits), None);
+            assert_eq!(dominates(&a[0], &a[0], Choise::Profits), None);
+            assert_eq!(
+                dominates(&a[1], &a[0], Choise::Losses),
+                Some(Dominance::Strict)
+            );
+        }
+
+        #[test]
+        fn test_non_dominated() {
+            let a = generate_test_data();
+
+            assert_eq!(dominance_relations(&a, Choise::Profits).len(), 3);
+            assert_eq!(non_dominated(&a, Choise::Profits), vec![0, 3]);
+            assert_eq!(non_dominated(&a, Choise::Losses), vec![1, 3]);
+        }
+
+        #[test]
+        fn test_criteria_direction() {
+            let a = vec![vec![10.0, 10.0], vec![1.0, 1.0]];
+
+            // Pruning by losses would leave maximax only the worse row.
+            let pruned: Vec<Vec<f32>> = non_dominated(&a, Choise::Losses)
+                .into_iter()
+                .map(|row| a[row].clone())
+                .collect();
+            assert_eq!(maximax(&a).1, vec![0]);
+            assert_eq!(maximax(&pruned).0, 1.0);
+
+            assert_eq!(criteria_direction(true, Choise::Losses), None);
+            assert_eq!(
+                criteria_direction(true, Choise::Profits),
+                Some(Choise::Profits)
+            );
+            assert_eq!(
+                criteria_direction(false, Choise::Losses),
+                Some(Choise::Profits)
+            );
+        }
+    }
+
+    mod payoff {
+        use std::fmt::Debug;
+
+        use crate::{
+            answer_block::profits_losses_radio::Choise,
+            criterion::{
+                risk_condition::{bayes_scores, modal_scores},
+                uncertainty::{hurwitz_scores, maximax_scores, minimax_scores, savage_scores},
+                Payoff,
+            },
+            fuzzy::FuzzyNumber,
+            interval::Interval,
+        };
+
+        /// Scores of a matrix of points must be points of the crisp scores.
+        fn check_point_matrix<T: Payoff + PartialEq + Debug>() {
+            let a = vec![
+                vec![45.0, 25.0, 50.0],
+                vec![20.0, 60.0, 25.0],
+                vec![30.0, 30.0, 30.0],
+            ];
+            let points: Vec<Vec<T>> = a
+                .iter()
+                .map(|row| row.iter().map(|&value| T::point(value)).collect())
+                .collect();
+            let p = vec![0.3, 0.3, 0.4];
+            let to_points = |scores: Vec<f32>| scores.into_iter().map(T::point).collect::<Vec<_>>();
+
+            for choise in [Choise::Profits, Choise::Losses] {
+                assert_eq!(
+                    minimax_scores(&points, choise),
+                    to_points(minimax_scores(&a, choise))
+                );
+            }
+            assert_eq!(maximax_scores(&points), to_points(maximax_scores(&a)));
+            assert_eq!(
+                hurwitz_scores(&points, 0.3),
+                to_points(hurwitz_scores(&a, 0.3))
+            );
+            assert_eq!(savage_scores(&points), to_points(savage_scores(&a)));
+            assert_eq!(bayes_scores(&points, &p), to_points(bayes_scores(&a, &p)));
+            assert_eq!(
+                modal_scores(&points, &p),
+                modal_scores(&a, &p).map(to_points)
+            );
+        }
+
+        #[test]
+        fn test_point_intervals() {
+            check_point_matrix::<Interval>();
+        }
+
+        #[test]
+        fn test_crisp_fuzzy_numbers() {
+            check_point_matrix::<FuzzyNumber>();
+        }
+    }
 }
@@ -0,0 +1,286 @@
+use iced::{
+    widget::{button, column, pick_list, row, text_input, Column, Text},
+    Element, Length,
+};
+
+use crate::constants::{TREE_INDENT, TREE_INPUT_WIDTH};
+
+use super::tree::{DecisionTree, Node, NodeKind, Rollback, RollbackError};
+
+struct EditorNode {
+    label: String,
+    kind: NodeKind,
+    payoff: String,
+    probability: String,
+    children: Vec<usize>,
+}
+
+pub struct TreeEditor {
+    nodes: Vec<EditorNode>,
+    rollback: Option<Rollback>,
+    pub error_text: String,
+}
+
+#[derive(Clone, Debug)]
+pub enum TreeEditorMessage {
+    LabelChanged(usize, String),
+    KindSelected(usize, NodeKind),
+    PayoffChanged(usize, String),
+    ProbabilityChanged(usize, String),
+    AddChild(usize),
+    Remove(usize),
+    FromMatrixButtonPressed,
+    EvaluateButtonPressed,
+}
+
+impl TreeEditor {
+    pub fn new() -> Self {
+        TreeEditor {
+            nodes: vec![EditorNode {
+                label: "Рішення".to_string(),
+                kind: NodeKind::Decision,
+                payoff: "0".to_string(),
+                probability: String::new(),
+                children: Vec::new(),
+            }],
+            rollback: None,
+            error_text: String::new(),
+        }
+    }
+
+    pub fn load(&mut self, tree: DecisionTree) {
+        self.nodes = tree
+            .nodes
+            .into_iter()
+            .map(|node| EditorNode {
+                label: node.label,
+                kind: node.kind,
+                payoff: node.payoff.to_string(),
+                probability: node.probability.to_string(),
+                children: node.children,
+            })
+            .collect();
+
+        self.evaluate();
+    }
+
+    pub fn update(&mut self, message: TreeEditorMessage) {
+        if !matches!(message, TreeEditorMessage::EvaluateButtonPressed) {
+            self.rollback = None;
+        }
+
+        match message {
+            TreeEditorMessage::LabelChanged(index, label) => self.nodes[index].label = label,
+            TreeEditorMessage::KindSelected(index, kind) => self.nodes[index].kind = kind,
+            TreeEditorMessage::PayoffChanged(index, payoff) => self.nodes[index].payoff = payoff,
+            TreeEditorMessage::ProbabilityChanged(index, probability) => {
+                self.nodes[index].probability = probability
+            }
+            TreeEditorMessage::AddChild(index) => {
+                let child_index = self.nodes.len();
+                self.nodes.push(EditorNode {
+                    label: format!("Вузол {}", child_index),
+                    kind: NodeKind::Terminal,
+                    payoff: "0".to_string(),
+                    probability: String::new(),
+                    children: Vec::new(),
+                });
+                self.nodes[index].children.push(child_index);
+            }
+            TreeEditorMessage::Remove(index) => self.remove_subtree(index),
+            TreeEditorMessage::EvaluateButtonPressed => self.evaluate(),
+            TreeEditorMessage::FromMatrixButtonPressed => {}
+        }
+    }
+
+    /// Removes the node together with its descendants and renumbers the
+    /// remaining nodes, so the arena only holds nodes reachable from the root.
+    fn remove_subtree(&mut self, index: usize) {
+        let mut removed = vec![false; self.nodes.len()];
+        let mut stack = vec![index];
+        while let Some(index) = stack.pop() {
+            removed[index] = true;
+            stack.extend(self.nodes[index].children.iter().copied());
+        }
+
+        let mut new_indeces = vec![None; self.nodes.len()];
+        let mut next_index = 0;
+        for (index, is_removed) in removed.iter().enumerate() {
+            if !is_removed {
+                new_indeces[index] = Some(next_index);
+                next_index += 1;
+            }
+        }
+
+        self.nodes = std::mem::take(&mut self.nodes)
+            .into_iter()
+            .zip(removed)
+            .filter(|(_, is_removed)| !is_removed)
+            .map(|(mut node, _)| {
+                node.children = node
+                    .children
+                    .iter()
+                    .filter_map(|&child| new_indeces[child])
+                    .collect();
+                node
+            })
+            .collect();
+    }
+
+    fn evaluate(&mut self) {
+        let rollback = self.to_tree().and_then(|tree| {
+            tree.rollback()
+                .map_err(|error| self.rollback_error_text(error))
+        });
+
+        match rollback {
+            Ok(rollback) => {
+                self.rollback = Some(rollback);
+                self.error_text = String::new();
+            }
+            Err(error_text) => {
+                self.rollback = None;
+                self.error_text = error_text;
+            }
+        }
+    }
+
+    /// Explains which node makes the tree invalid.
+    fn rollback_error_text(&self, error: RollbackError) -> String {
+        match error {
+            RollbackError::EmptyTree => "Дерево рішень порожнє.".to_string(),
+            RollbackError::TerminalWithChildren(index) => format!(
+                "Кінцевий вузол «{}» не може мати нащадків, видаліть їх або змініть тип вузла.",
+                self.nodes[index].label
+            ),
+            RollbackError::MissingChildren(index) => {
+                format!("Вузол «{}» має мати нащадків.", self.nodes[index].label)
+            }
+            RollbackError::InvalidProbabilities(index) => format!(
+                "Ймовірності нащадків вузла «{}» мають бути в межах [0; 1] та в сумі дорівнювати 1.",
+                self.nodes[index].label
+            ),
+        }
+    }
+
+    fn has_chance_parent(&self, index: usize) -> bool {
+        self.nodes
+            .iter()
+            .any(|parent| parent.kind == NodeKind::Chance && parent.children.contains(&index))
+    }
+
+    fn to_tree(&self) -> Result<DecisionTree, String> {
+        let mut nodes = Vec::with_capacity(self.nodes.len());
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            let payoff = node
+                .payoff
+                .parse::<f32>()
+                .map_err(|_| format!("Виграш вузла «{}» некоректний.", node.label))?;
+
+            let probability = if self.has_chance_parent(index) {
+                node.probability
+                    .parse::<f32>()
+                    .map_err(|_| format!("Ймовірність вузла «{}» некоректна.", node.label))?
+            } else {
+                0.0
+            };
+
+            nodes.push(Node {
+                label: node.label.clone(),
+                kind: node.kind,
+                payoff,
+                probability,
+                children: node.children.clone(),
+            });
+        }
+
+        Ok(DecisionTree { nodes })
+    }
+
+    pub fn view(&self) -> Element<'_, TreeEditorMessage> {
+        let mut rows = Vec::new();
+        self.push_node_rows(0, 0, None, &mut rows);
+
+        let mut content = column![
+            Text::new("Дерево рішень").size(24),
+            Column::with_children(rows).spacing(5),
+            row![
+                button("З матриці").on_press(TreeEditorMessage::FromMatrixButtonPressed),
+                button("Обрахувати").on_press(TreeEditorMessage::EvaluateButtonPressed),
+            ]
+            .spacing(10),
+        ]
+        .spacing(10);
+
+        if !self.error_text.is_empty() {
+            content = content.push(Text::new(self.error_text.clone()));
+        }
+
+        content.into()
+    }
+
+    fn push_node_rows<'a>(
+        &'a self,
+        index: usize,
+        depth: usize,
+        parent_kind: Option<NodeKind>,
+        rows: &mut Vec<Element<'a, TreeEditorMessage>>,
+    ) {
+        let node = &self.nodes[index];
+
+        let mut node_row = row![
+            column![].width(Length::Fixed(TREE_INDENT * depth as f32)),
+            text_input("Назва", &node.label)
+                .on_input(move |label| TreeEditorMessage::LabelChanged(index, label))
+                .width(Length::Fixed(TREE_INPUT_WIDTH)),
+            pick_list(&NodeKind::ALL[..], Some(node.kind), move |kind| {
+                TreeEditorMessage::KindSelected(index, kind)
+            }),
+            text_input("Виграш", &node.payoff)
+                .on_input(move |payoff| TreeEditorMessage::PayoffChanged(index, payoff))
+                .width(Length::Fixed(TREE_INPUT_WIDTH)),
+        ]
+        .spacing(5)
+        .align_items(iced::Alignment::Center);
+
+        if parent_kind == Some(NodeKind::Chance) {
+            node_row = node_row.push(
+                text_input("p", &node.probability)
+                    .on_input(move |probability| {
+                        TreeEditorMessage::ProbabilityChanged(index, probability)
+                    })
+                    .width(Length::Fixed(TREE_INPUT_WIDTH / 2.0)),
+            );
+        }
+
+        if node.kind != NodeKind::Terminal {
+            node_row = node_row.push(button("+").on_press(TreeEditorMessage::AddChild(index)));
+        }
+
+        if index != 0 {
+            node_row = node_row.push(button("×").on_press(TreeEditorMessage::Remove(index)));
+        }
+
+        if let Some(Some((value, _))) = self.rollback.as_ref().map(|rollback| &rollback[index]) {
+            node_row = node_row.push(Text::new(format!("EV = {}", value)));
+        }
+
+        if let Some(rollback) = &self.rollback {
+            let is_optimal = rollback
+                .iter()
+                .flatten()
+                .any(|(_, optimal_children)| optimal_children.contains(&index));
+
+            if is_optimal {
+                node_row = node_row.push(Text::new("оптимально"));
+            }
+        }
+
+        rows.push(node_row.into());
+
+        for &child in &node.children {
+            self.push_node_rows(child, depth + 1, Some(node.kind), rows);
+        }
+    }
+}
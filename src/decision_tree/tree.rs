@@ -0,0 +1,272 @@
+use std::fmt::Display;
+
+use crate::criterion::{get_indeces, get_max, risk_condition::bayes};
+
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    Decision,
+    Chance,
+    Terminal,
+}
+
+impl NodeKind {
+    pub const ALL: [NodeKind; 3] = [NodeKind::Decision, NodeKind::Chance, NodeKind::Terminal];
+}
+
+impl Display for NodeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                NodeKind::Decision => "Рішення",
+                NodeKind::Chance => "Випадок",
+                NodeKind::Terminal => "Кінцевий",
+            }
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Node {
+    pub label: String,
+    pub kind: NodeKind,
+    /// Payoff collected on the branch leading to the node. For terminal nodes
+    /// it is the final outcome.
+    pub payoff: f32,
+    /// Probability of the branch, used when the parent is a chance node.
+    pub probability: f32,
+    pub children: Vec<usize>,
+}
+
+impl Node {
+    pub fn new(label: String, kind: NodeKind) -> Self {
+        Node {
+            label,
+            kind,
+            payoff: 0.0,
+            probability: 0.0,
+            children: Vec::new(),
+        }
+    }
+}
+
+/// Value and optimal children of every node, `None` for unreachable nodes.
+pub type Rollback = Vec<Option<(f32, Vec<usize>)>>;
+
+/// Reason the tree can't be rolled back, with the index of the offending node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RollbackError {
+    EmptyTree,
+    TerminalWithChildren(usize),
+    MissingChildren(usize),
+    /// Probabilities of the children of a chance node are out of `[0; 1]` or
+    /// don`t sum up to 1.
+    InvalidProbabilities(usize),
+}
+
+/// Decision tree stored as an arena of nodes, the root has index 0.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DecisionTree {
+    pub nodes: Vec<Node>,
+}
+
+impl DecisionTree {
+    /// Equivalent single-stage tree: a decision between alternatives, each
+    /// followed by a chance node over states of nature.
+    pub fn from_matrix(a: &[Vec<f32>], p: &[f32]) -> Self {
+        let mut nodes = vec![Node::new("Рішення".to_string(), NodeKind::Decision)];
+
+        for (row_index, row) in a.iter().enumerate() {
+            let chance_index = nodes.len();
+            nodes[0].children.push(chance_index);
+            nodes.push(Node::new(format!("x{}", row_index + 1), NodeKind::Chance));
+
+            for (col_index, (value, probability)) in row.iter().zip(p.iter()).enumerate() {
+                let terminal_index = nodes.len();
+                nodes[chance_index].children.push(terminal_index);
+                nodes.push(Node {
+                    payoff: *value,
+                    probability: *probability,
+                    ..Node::new(format!("y{}", col_index + 1), NodeKind::Terminal)
+                });
+            }
+        }
+
+        DecisionTree { nodes }
+    }
+
+    /// Rolls the tree back from the leaves to the root.
+    ///
+    /// Chance nodes take the expected value of their children (Bayes criterion
+    /// over a single alternative), decision nodes take the best child. Returns
+    /// the value and optimal children of every node reachable from the root.
+    pub fn rollback(&self) -> Result<Rollback, RollbackError> {
+        if self.nodes.is_empty() {
+            return Err(RollbackError::EmptyTree);
+        }
+
+        let mut values = vec![None; self.nodes.len()];
+        self.rollback_node(0, &mut values)?;
+
+        Ok(values)
+    }
+
+    fn rollback_node(&self, index: usize, values: &mut Rollback) -> Result<f32, RollbackError> {
+        let node = &self.nodes[index];
+
+        let child_values = node
+            .children
+            .iter()
+            .map(|&child| self.rollback_node(child, values))
+            .collect::<Result<Vec<f32>, _>>()?;
+
+        let (value, optimal_children) = match node.kind {
+            NodeKind::Terminal => {
+                if !node.children.is_empty() {
+                    return Err(RollbackError::TerminalWithChildren(index));
+                }
+
+                (node.payoff, Vec::new())
+            }
+            NodeKind::Chance => {
+                if child_values.is_empty() {
+                    return Err(RollbackError::MissingChildren(index));
+                }
+
+                let p: Vec<f32> = node
+                    .children
+                    .iter()
+                    .map(|&child| self.nodes[child].probability)
+                    .collect();
+
+                if p.iter()
+                    .any(|probability| !(0.0..=1.0).contains(probability))
+                    || (p.iter().sum::<f32>() - 1.0).abs() > 1e-4
+                {
+                    return Err(RollbackError::InvalidProbabilities(index));
+                }
+
+                let expected_value = bayes(&[child_values], &p).0;
+
+                (node.payoff + expected_value, Vec::new())
+            }
+            NodeKind::Decision => {
+                if child_values.is_empty() {
+                    return Err(RollbackError::MissingChildren(index));
+                }
+
+                let best_value = get_max(&child_values);
+                let optimal_children = get_indeces(&child_values, best_value)
+                    .into_iter()
+                    .map(|position| node.children[position])
+                    .collect();
+
+                (node.payoff + best_value, optimal_children)
+            }
+        };
+
+        values[index] = Some((value, optimal_children));
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        criterion::risk_condition::bayes,
+        decision_tree::tree::{DecisionTree, Node, NodeKind, RollbackError},
+    };
+
+    #[test]
+    fn test_rollback_matches_bayes() {
+        let a = vec![
+            vec![100_000.0, -50_000.0, -50_000.0],
+            vec![-50_000.0, -50_000.0, 100_000.0],
+            vec![15_000.0, 15_000.0, 0.0],
+            vec![0.0, 0.0, 0.0],
+        ];
+        let p = vec![0.5, 0.1, 0.4];
+
+        let tree = DecisionTree::from_matrix(&a, &p);
+        let values = tree.rollback().unwrap();
+        let (root_value, optimal_children) = values[0].clone().unwrap();
+
+        let (bayes_value, bayes_indeces) = bayes(&a, &p);
+
+        assert_eq!(root_value, bayes_value, "Rollback gives incorrect value.");
+        assert_eq!(
+            optimal_children,
+            bayes_indeces
+                .iter()
+                .map(|&index| tree.nodes[0].children[index])
+                .collect::<Vec<_>>(),
+            "Rollback chooses incorrect alternative."
+        );
+    }
+
+    #[test]
+    fn test_rollback_two_stages() {
+        // Pay 10 for a test which tells the state exactly, or decide right away.
+        let mut nodes = vec![
+            Node::new("Рішення".to_string(), NodeKind::Decision),
+            Node {
+                payoff: -10.0,
+                ..Node::new("Тест".to_string(), NodeKind::Chance)
+            },
+            Node {
+                payoff: 30.0,
+                ..Node::new("Без тесту".to_string(), NodeKind::Terminal)
+            },
+        ];
+        nodes[0].children = vec![1, 2];
+
+        for payoff in [100.0, 0.0] {
+            let index = nodes.len();
+            nodes[1].children.push(index);
+            nodes.push(Node {
+                payoff,
+                probability: 0.5,
+                ..Node::new("y".to_string(), NodeKind::Terminal)
+            });
+        }
+
+        let values = DecisionTree { nodes }.rollback().unwrap();
+
+        assert_eq!(values[1].as_ref().unwrap().0, 40.0);
+        assert_eq!(values[0], Some((40.0, vec![1])));
+    }
+
+    #[test]
+    fn test_rollback_invalid_probabilities() {
+        let mut tree = DecisionTree::from_matrix(&[vec![1.0, 2.0]], &[0.5, 0.4]);
+
+        assert_eq!(
+            tree.rollback(),
+            Err(RollbackError::InvalidProbabilities(1)),
+            "Probabilities don`t sum up to 1."
+        );
+
+        tree.nodes[3].probability = 0.5;
+        assert!(tree.rollback().is_ok());
+    }
+
+    #[test]
+    fn test_rollback_invalid_structure() {
+        let mut tree = DecisionTree::from_matrix(&[vec![1.0, 2.0]], &[0.5, 0.5]);
+        tree.nodes[2].children.push(3);
+
+        assert_eq!(tree.rollback(), Err(RollbackError::TerminalWithChildren(2)));
+
+        tree.nodes[2].children.clear();
+        tree.nodes[1].kind = NodeKind::Decision;
+        tree.nodes[2].kind = NodeKind::Chance;
+
+        assert_eq!(tree.rollback(), Err(RollbackError::MissingChildren(2)));
+        assert_eq!(
+            DecisionTree::default().rollback(),
+            Err(RollbackError::EmptyTree)
+        );
+    }
+}
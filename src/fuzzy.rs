@@ -0,0 +1,333 @@
+use std::fmt::Display;
+
+use crate::{
+    answer_block::profits_losses_radio::Choise,
+    criterion::{
+        risk_condition::{bayes_scores, modal_scores},
+        uncertainty::{hurwitz_scores, maximax_scores, minimax_scores, savage_scores},
+        Payoff,
+    },
+    utils::parse_list,
+};
+
+/// Trapezoidal fuzzy number `(a; b; c; d)`: membership grows on `[a, b]`,
+/// equals 1 on `[b, c]` and falls on `[c, d]`. Triangular numbers have
+/// `b = c`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FuzzyNumber {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+}
+
+impl FuzzyNumber {
+    pub fn new(a: f32, b: f32, c: f32, d: f32) -> Self {
+        FuzzyNumber { a, b, c, d }
+    }
+
+    pub fn crisp(value: f32) -> Self {
+        FuzzyNumber::new(value, value, value, value)
+    }
+
+    /// Parses a number, a triangular `(a; b; c)` or a trapezoidal
+    /// `(a; b; c; d)` fuzzy number.
+    pub fn parse(cell: &str) -> Result<Self, &'static str> {
+        let cell = cell.trim();
+
+        if let Ok(value) = cell.parse::<f32>() {
+            return Ok(FuzzyNumber::crisp(value));
+        }
+
+        let points = cell
+            .strip_prefix('(')
+            .and_then(|cell| cell.strip_suffix(')'))
+            .ok_or("Fuzzy number is invalid.")?;
+
+        let number = match parse_list(points).map_err(|_| "Fuzzy number is invalid.")?[..] {
+            [a, b, c] => FuzzyNumber::new(a, b, b, c),
+            [a, b, c, d] => FuzzyNumber::new(a, b, c, d),
+            _ => return Err("Fuzzy number is invalid."),
+        };
+
+        if number.a <= number.b && number.b <= number.c && number.c <= number.d {
+            Ok(number)
+        } else {
+            Err("Points of fuzzy number must not decrease.")
+        }
+    }
+
+    pub fn is_crisp(&self) -> bool {
+        self.a == self.d
+    }
+
+    pub fn defuzzify(&self, method: Defuzzification) -> f32 {
+        let FuzzyNumber { a, b, c, d } = *self;
+
+        match method {
+            Defuzzification::Centroid => {
+                let denominator = 3.0 * (d + c - a - b);
+
+                if denominator == 0.0 {
+                    a
+                } else {
+                    (d * d + c * c + c * d - a * a - b * b - a * b) / denominator
+                }
+            }
+            Defuzzification::GradedMean => (a + 2.0 * b + 2.0 * c + d) / 6.0,
+        }
+    }
+}
+
+impl Payoff for FuzzyNumber {
+    fn point(value: f32) -> Self {
+        FuzzyNumber::crisp(value)
+    }
+
+    fn add(self, other: FuzzyNumber) -> FuzzyNumber {
+        FuzzyNumber::new(
+            self.a + other.a,
+            self.b + other.b,
+            self.c + other.c,
+            self.d + other.d,
+        )
+    }
+
+    fn sub(self, other: FuzzyNumber) -> FuzzyNumber {
+        FuzzyNumber::new(
+            self.a - other.d,
+            self.b - other.c,
+            self.c - other.b,
+            self.d - other.a,
+        )
+    }
+
+    fn scale(self, k: f32) -> FuzzyNumber {
+        FuzzyNumber::new(k * self.a, k * self.b, k * self.c, k * self.d)
+    }
+
+    /// Minimum by points, the usual approximation for trapezoidal numbers.
+    fn min(self, other: FuzzyNumber) -> FuzzyNumber {
+        FuzzyNumber::new(
+            self.a.min(other.a),
+            self.b.min(other.b),
+            self.c.min(other.c),
+            self.d.min(other.d),
+        )
+    }
+
+    fn max(self, other: FuzzyNumber) -> FuzzyNumber {
+        FuzzyNumber::new(
+            self.a.max(other.a),
+            self.b.max(other.b),
+            self.c.max(other.c),
+            self.d.max(other.d),
+        )
+    }
+}
+
+impl Display for FuzzyNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.b == self.c {
+            write!(f, "({:.4}; {:.4}; {:.4})", self.a, self.b, self.d)
+        } else {
+            write!(
+                f,
+                "({:.4}; {:.4}; {:.4}; {:.4})",
+                self.a, self.b, self.c, self.d
+            )
+        }
+    }
+}
+
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+pub enum Defuzzification {
+    /// Abscissa of the centre of gravity of the membership function.
+    Centroid,
+    /// Graded mean integration `(a + 2b + 2c + d) / 6`.
+    GradedMean,
+}
+
+impl Defuzzification {
+    pub const ALL: [Defuzzification; 2] = [Defuzzification::Centroid, Defuzzification::GradedMean];
+}
+
+impl Display for Defuzzification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Defuzzification::Centroid => "Центр ваги",
+                Defuzzification::GradedMean => "Зважене середнє",
+            }
+        )
+    }
+}
+
+/// Fuzzy scores of alternatives.
+pub struct FuzzyDecision {
+    pub scores: Vec<FuzzyNumber>,
+    /// Savage and Wald over losses minimize the score, the others maximize.
+    pub minimize: bool,
+}
+
+impl FuzzyDecision {
+    pub fn defuzzified(&self, method: Defuzzification) -> Vec<f32> {
+        self.scores
+            .iter()
+            .map(|score| score.defuzzify(method))
+            .collect()
+    }
+
+    /// Alternatives from the best defuzzified score to the worst.
+    pub fn ranking(&self, method: Defuzzification) -> Vec<usize> {
+        let values = self.defuzzified(method);
+        let mut order: Vec<usize> = (0..values.len()).collect();
+
+        order.sort_by(|&x, &y| {
+            let ordering = values[y].partial_cmp(&values[x]).unwrap();
+
+            if self.minimize {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+
+        order
+    }
+}
+
+pub fn wald(a: &[Vec<FuzzyNumber>], profits_losses: Choise) -> FuzzyDecision {
+    FuzzyDecision {
+        scores: minimax_scores(a, profits_losses),
+        minimize: profits_losses == Choise::Losses,
+    }
+}
+
+pub fn maximax(a: &[Vec<FuzzyNumber>]) -> FuzzyDecision {
+    FuzzyDecision {
+        scores: maximax_scores(a),
+        minimize: false,
+    }
+}
+
+pub fn hurwitz(a: &[Vec<FuzzyNumber>], alpha: f32) -> FuzzyDecision {
+    FuzzyDecision {
+        scores: hurwitz_scores(a, alpha),
+        minimize: false,
+    }
+}
+
+/// Maximal fuzzy regrets.
+pub fn savage(a: &[Vec<FuzzyNumber>]) -> FuzzyDecision {
+    FuzzyDecision {
+        scores: savage_scores(a),
+        minimize: true,
+    }
+}
+
+pub fn bayes(a: &[Vec<FuzzyNumber>], p: &[f32]) -> FuzzyDecision {
+    FuzzyDecision {
+        scores: bayes_scores(a, p),
+        minimize: false,
+    }
+}
+
+/// `None` when several states are the most probable.
+pub fn modal(a: &[Vec<FuzzyNumber>], p: &[f32]) -> Option<FuzzyDecision> {
+    Some(FuzzyDecision {
+        scores: modal_scores(a, p)?,
+        minimize: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answer_block::profits_losses_radio::Choise,
+        criterion::Payoff,
+        fuzzy::{maximax, wald, Defuzzification, FuzzyNumber},
+    };
+
+    #[test]
+    fn test_parse_fuzzy_number() {
+        assert_eq!(
+            FuzzyNumber::parse("(1; 2; 3)"),
+            Ok(FuzzyNumber::new(1.0, 2.0, 2.0, 3.0))
+        );
+        assert_eq!(
+            FuzzyNumber::parse(" (0; 1; 3; 4) "),
+            Ok(FuzzyNumber::new(0.0, 1.0, 3.0, 4.0))
+        );
+        assert_eq!(FuzzyNumber::parse("7"), Ok(FuzzyNumber::crisp(7.0)));
+        assert!(FuzzyNumber::parse("(3; 2; 1)").is_err());
+        assert!(FuzzyNumber::parse("(1; 2)").is_err());
+        assert!(FuzzyNumber::parse("[1; 2]").is_err());
+    }
+
+    #[test]
+    fn test_defuzzify() {
+        let symmetric = FuzzyNumber::new(0.0, 1.0, 3.0, 4.0);
+        let skewed = FuzzyNumber::new(0.0, 0.0, 0.0, 6.0);
+
+        assert_eq!(symmetric.defuzzify(Defuzzification::Centroid), 2.0);
+        assert_eq!(symmetric.defuzzify(Defuzzification::GradedMean), 2.0);
+        assert_eq!(skewed.defuzzify(Defuzzification::Centroid), 2.0);
+        assert_eq!(skewed.defuzzify(Defuzzification::GradedMean), 1.0);
+        assert_eq!(
+            FuzzyNumber::crisp(5.0).defuzzify(Defuzzification::Centroid),
+            5.0
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let x = FuzzyNumber::new(1.0, 2.0, 2.0, 3.0);
+        let y = FuzzyNumber::new(0.0, 1.0, 2.0, 4.0);
+
+        assert_eq!(x.add(y), FuzzyNumber::new(1.0, 3.0, 4.0, 7.0));
+        assert_eq!(x.sub(y), FuzzyNumber::new(-3.0, 0.0, 1.0, 3.0));
+        assert_eq!(y.scale(0.5), FuzzyNumber::new(0.0, 0.5, 1.0, 2.0));
+        assert_eq!(x.min(y), FuzzyNumber::new(0.0, 1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_fuzzy_ranking_depends_on_method() {
+        // The centroid follows the long right tail, the graded mean stays
+        // close to the mode.
+        let a = vec![
+            vec![FuzzyNumber::new(0.0, 0.0, 0.0, 6.0)],
+            vec![FuzzyNumber::new(1.0, 1.5, 1.5, 3.0)],
+        ];
+
+        let decision = maximax(&a);
+
+        assert_eq!(decision.ranking(Defuzzification::Centroid), vec![0, 1]);
+        assert_eq!(decision.ranking(Defuzzification::GradedMean), vec![1, 0]);
+        assert_eq!(decision.scores[0].to_string(), "(0.0000; 0.0000; 6.0000)");
+    }
+
+    #[test]
+    fn test_wald_losses() {
+        let a = vec![
+            vec![
+                FuzzyNumber::new(1.0, 2.0, 2.0, 3.0),
+                FuzzyNumber::crisp(8.0),
+            ],
+            vec![
+                FuzzyNumber::crisp(5.0),
+                FuzzyNumber::new(4.0, 5.0, 5.0, 6.0),
+            ],
+        ];
+
+        // The largest loss of the second alternative is smaller.
+        let decision = wald(&a, Choise::Losses);
+        assert_eq!(decision.scores[1], FuzzyNumber::new(5.0, 5.0, 5.0, 6.0));
+        assert_eq!(decision.ranking(Defuzzification::Centroid), vec![1, 0]);
+
+        let decision = wald(&a, Choise::Profits);
+        assert_eq!(decision.ranking(Defuzzification::Centroid), vec![1, 0]);
+    }
+}
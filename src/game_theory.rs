@@ -0,0 +1,436 @@
+use crate::{
+    answer_block::profits_losses_radio::Choise,
+    criterion::{get_indeces, get_max, get_min, uncertainty::minimax},
+};
+
+/// Solution of a two-person zero-sum game where the row player maximizes
+/// the payoff from the matrix.
+#[derive(Debug, PartialEq)]
+pub struct GameSolution {
+    /// Maximin over rows (lower value of the game).
+    pub lower_value: f32,
+    /// Minimax over columns (upper value of the game).
+    pub upper_value: f32,
+    pub saddle_point: Option<(usize, usize)>,
+    /// Strategies left after removing dominated ones.
+    pub kept_rows: Vec<usize>,
+    pub kept_cols: Vec<usize>,
+    pub value: f32,
+    pub row_strategy: Vec<f32>,
+    pub col_strategy: Vec<f32>,
+}
+
+pub fn lower_upper_values(a: &[Vec<f32>]) -> (f32, f32) {
+    let lower_value = minimax(a, Choise::Profits).0;
+
+    let cols = if !a.is_empty() { a[0].len() } else { 0 };
+    let column_maxes: Vec<f32> = (0..cols)
+        .map(|col_index| get_max(&a.iter().map(|row| row[col_index]).collect::<Vec<_>>()))
+        .collect();
+
+    (lower_value, get_min(&column_maxes))
+}
+
+/// Cell which is the minimum of its row and the maximum of its column.
+pub fn saddle_point(a: &[Vec<f32>]) -> Option<(usize, usize)> {
+    let (lower_value, upper_value) = lower_upper_values(a);
+
+    if lower_value != upper_value {
+        return None;
+    }
+
+    a.iter().enumerate().find_map(|(row_index, row)| {
+        row.iter().enumerate().find_map(|(col_index, &value)| {
+            let is_row_min = value == get_min(row);
+            let is_col_max = a.iter().all(|other_row| other_row[col_index] <= value);
+
+            (is_row_min && is_col_max).then_some((row_index, col_index))
+        })
+    })
+}
+
+/// Iteratively removes weakly dominated rows (row player maximizes) and
+/// columns (column player minimizes). Returns indeces of remaining rows and
+/// columns.
+pub fn remove_dominated(a: &[Vec<f32>]) -> (Vec<usize>, Vec<usize>) {
+    let mut rows: Vec<usize> = (0..a.len()).collect();
+    let mut cols: Vec<usize> = (0..if !a.is_empty() { a[0].len() } else { 0 }).collect();
+
+    loop {
+        let dominated_row = rows.iter().position(|&row| {
+            rows.iter()
+                .any(|&other| other != row && cols.iter().all(|&col| a[other][col] >= a[row][col]))
+        });
+        if let Some(position) = dominated_row {
+            rows.remove(position);
+            continue;
+        }
+
+        let dominated_col = cols.iter().position(|&col| {
+            cols.iter()
+                .any(|&other| other != col && rows.iter().all(|&row| a[row][other] <= a[row][col]))
+        });
+        if let Some(position) = dominated_col {
+            cols.remove(position);
+            continue;
+        }
+
+        break;
+    }
+
+    (rows, cols)
+}
+
+/// Optimal mixed strategies and value of the game.
+///
+/// The matrix is shifted to positive values and the column player's problem
+/// `max Σy, Ay <= 1, y >= 0` is solved with the simplex method. The row
+/// player's strategy is read from the dual values of the final tableau.
+pub fn solve_mixed(a: &[Vec<f32>]) -> (f32, Vec<f32>, Vec<f32>) {
+    let rows = a.len();
+    let cols = a[0].len();
+
+    let min = get_min(&a.iter().map(|row| get_min(row)).collect::<Vec<_>>());
+    let shift = if min <= 0.0 { 1.0 - min } else { 0.0 };
+
+    // Tableau columns: y_1..y_n, s_1..s_m, right-hand side.
+    let width = cols + rows + 1;
+    let mut tableau: Vec<Vec<f64>> = a
+        .iter()
+        .enumerate()
+        .map(|(row_index, row)| {
+            let mut tableau_row = vec![0.0; width];
+            for (col_index, value) in row.iter().enumerate() {
+                tableau_row[col_index] = (value + shift) as f64;
+            }
+            tableau_row[cols + row_index] = 1.0;
+            tableau_row[width - 1] = 1.0;
+            tableau_row
+        })
+        .collect();
+
+    let mut objective = vec![0.0; width];
+    objective[..cols].iter_mut().for_each(|value| *value = -1.0);
+
+    let mut basis: Vec<usize> = (cols..cols + rows).collect();
+
+    // Bland's rule: the first improving column and the first minimal ratio.
+    while let Some(pivot_col) = (0..width - 1).find(|&col| objective[col] < -1e-9) {
+        let pivot_row = (0..rows)
+            .filter(|&row| tableau[row][pivot_col] > 1e-9)
+            .min_by(|&x, &y| {
+                let ratio_x = tableau[x][width - 1] / tableau[x][pivot_col];
+                let ratio_y = tableau[y][width - 1] / tableau[y][pivot_col];
+                ratio_x
+                    .partial_cmp(&ratio_y)
+                    .unwrap()
+                    .then(basis[x].cmp(&basis[y]))
+            })
+            .expect("Game LP is bounded for a positive matrix.");
+
+        let pivot = tableau[pivot_row][pivot_col];
+        tableau[pivot_row]
+            .iter_mut()
+            .for_each(|value| *value /= pivot);
+
+        let pivot_values = tableau[pivot_row].clone();
+        for (row_index, row) in tableau.iter_mut().enumerate() {
+            if row_index != pivot_row {
+                let factor = row[pivot_col];
+                row.iter_mut()
+                    .zip(pivot_values.iter())
+                    .for_each(|(value, pivot_value)| *value -= factor * pivot_value);
+            }
+        }
+
+        let factor = objective[pivot_col];
+        objective
+            .iter_mut()
+            .zip(pivot_values.iter())
+            .for_each(|(value, pivot_value)| *value -= factor * pivot_value);
+
+        basis[pivot_row] = pivot_col;
+    }
+
+    let sum = objective[width - 1];
+    let shifted_value = 1.0 / sum;
+
+    let mut col_strategy = vec![0.0; cols];
+    for (row_index, &basic) in basis.iter().enumerate() {
+        if basic < cols {
+            col_strategy[basic] = (tableau[row_index][width - 1] * shifted_value) as f32;
+        }
+    }
+
+    let row_strategy = (0..rows)
+        .map(|row_index| (objective[cols + row_index] * shifted_value) as f32)
+        .collect();
+
+    (
+        (shifted_value - shift as f64) as f32,
+        row_strategy,
+        col_strategy,
+    )
+}
+
+pub fn solve(a: &[Vec<f32>]) -> GameSolution {
+    let (lower_value, upper_value) = lower_upper_values(a);
+    let saddle_point = saddle_point(a);
+    let (kept_rows, kept_cols) = remove_dominated(a);
+
+    let cols = if !a.is_empty() { a[0].len() } else { 0 };
+    let mut row_strategy = vec![0.0; a.len()];
+    let mut col_strategy = vec![0.0; cols];
+
+    let value = match saddle_point {
+        Some((row, col)) => {
+            row_strategy[row] = 1.0;
+            col_strategy[col] = 1.0;
+
+            a[row][col]
+        }
+        None => {
+            let reduced: Vec<Vec<f32>> = kept_rows
+                .iter()
+                .map(|&row| kept_cols.iter().map(|&col| a[row][col]).collect())
+                .collect();
+
+            let (value, reduced_row_strategy, reduced_col_strategy) = solve_mixed(&reduced);
+
+            for (&row, probability) in kept_rows.iter().zip(reduced_row_strategy) {
+                row_strategy[row] = probability;
+            }
+            for (&col, probability) in kept_cols.iter().zip(reduced_col_strategy) {
+                col_strategy[col] = probability;
+            }
+
+            value
+        }
+    };
+
+    GameSolution {
+        lower_value,
+        upper_value,
+        saddle_point,
+        kept_rows,
+        kept_cols,
+        value,
+        row_strategy,
+        col_strategy,
+    }
+}
+
+pub struct BrownRobinsonIteration {
+    pub row_choice: usize,
+    pub col_choice: usize,
+    /// Accumulated payoffs of row strategies against the column player's choices.
+    pub row_gains: Vec<f32>,
+    /// Accumulated payoffs of column strategies against the row player's choices.
+    pub col_losses: Vec<f32>,
+    pub upper_bound: f32,
+    pub lower_bound: f32,
+}
+
+pub struct BrownRobinsonSolution {
+    /// First iterations with accumulated payoffs.
+    pub iterations: Vec<BrownRobinsonIteration>,
+    /// Upper and lower estimates of the value on every played iteration.
+    pub bounds: Vec<(f32, f32)>,
+    /// Best (minimal) upper and best (maximal) lower estimates of the value.
+    pub upper_bound: f32,
+    pub lower_bound: f32,
+    pub value: f32,
+    pub row_strategy: Vec<f32>,
+    pub col_strategy: Vec<f32>,
+    pub converged: bool,
+}
+
+/// Brown–Robinson fictitious play: each player responds with the best pure
+/// strategy against the accumulated choices of the opponent. Stops after
+/// `max_iterations` or when the gap between value bounds drops to `tolerance`.
+/// Accumulated payoffs are kept only for the first `recorded_iterations`.
+pub fn brown_robinson(
+    a: &[Vec<f32>],
+    max_iterations: usize,
+    tolerance: f32,
+    recorded_iterations: usize,
+) -> BrownRobinsonSolution {
+    let rows = a.len();
+    let cols = a[0].len();
+
+    let mut row_gains = vec![0.0; rows];
+    let mut col_losses = vec![0.0; cols];
+    let mut row_counts = vec![0; rows];
+    let mut col_counts = vec![0; cols];
+
+    let mut iterations = Vec::with_capacity(recorded_iterations.min(max_iterations));
+    let mut bounds = Vec::new();
+    let mut upper_bound = f32::INFINITY;
+    let mut lower_bound = f32::NEG_INFINITY;
+    let mut converged = false;
+
+    // The first row is chosen by the maximin principle.
+    let mut row_choice = minimax(a, Choise::Profits).1[0];
+
+    for k in 1..=max_iterations {
+        col_losses
+            .iter_mut()
+            .zip(a[row_choice].iter())
+            .for_each(|(loss, value)| *loss += value);
+        let col_choice = get_indeces(&col_losses, get_min(&col_losses))[0];
+
+        row_gains
+            .iter_mut()
+            .zip(a.iter())
+            .for_each(|(gain, row)| *gain += row[col_choice]);
+
+        row_counts[row_choice] += 1;
+        col_counts[col_choice] += 1;
+
+        let iteration_upper_bound = get_max(&row_gains) / k as f32;
+        let iteration_lower_bound = get_min(&col_losses) / k as f32;
+        upper_bound = upper_bound.min(iteration_upper_bound);
+        lower_bound = lower_bound.max(iteration_lower_bound);
+
+        if k <= recorded_iterations {
+            iterations.push(BrownRobinsonIteration {
+                row_choice,
+                col_choice,
+                row_gains: row_gains.clone(),
+                col_losses: col_losses.clone(),
+                upper_bound: iteration_upper_bound,
+                lower_bound: iteration_lower_bound,
+            });
+        }
+        bounds.push((iteration_upper_bound, iteration_lower_bound));
+
+        if upper_bound - lower_bound <= tolerance {
+            converged = true;
+            break;
+        }
+
+        row_choice = get_indeces(&row_gains, get_max(&row_gains))[0];
+    }
+
+    let played = bounds.len() as f32;
+
+    BrownRobinsonSolution {
+        iterations,
+        bounds,
+        upper_bound,
+        lower_bound,
+        value: (upper_bound + lower_bound) / 2.0,
+        row_strategy: row_counts
+            .iter()
+            .map(|&count| count as f32 / played)
+            .collect(),
+        col_strategy: col_counts
+            .iter()
+            .map(|&count| count as f32 / played)
+            .collect(),
+        converged,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game_theory::{brown_robinson, remove_dominated, saddle_point, solve, solve_mixed};
+
+    fn assert_close(actual: &[f32], expected: &[f32], message: &str) {
+        assert!(
+            actual
+                .iter()
+                .zip(expected.iter())
+                .all(|(x, y)| (x - y).abs() < 1e-4),
+            "{message}: {actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn test_saddle_point() {
+        let a = vec![vec![3.0, 5.0], vec![1.0, 2.0]];
+
+        assert_eq!(saddle_point(&a), Some((0, 0)), "Saddle point is incorrect.");
+        assert_eq!(
+            saddle_point(&[vec![1.0, -1.0], vec![-1.0, 1.0]]),
+            None,
+            "Matching pennies has no saddle point."
+        );
+    }
+
+    #[test]
+    fn test_remove_dominated() {
+        // Row 3 is dominated by row 1, then column 3 is dominated by column 2.
+        let a = vec![
+            vec![4.0, 1.0, 3.0],
+            vec![2.0, 3.0, 4.0],
+            vec![3.0, 0.0, 2.0],
+        ];
+
+        assert_eq!(remove_dominated(&a), (vec![0, 1], vec![0, 1]));
+    }
+
+    #[test]
+    fn test_solve_mixed() {
+        let (value, row_strategy, col_strategy) = solve_mixed(&[vec![2.0, -1.0], vec![-1.0, 1.0]]);
+
+        assert!(
+            (value - 0.2).abs() < 1e-4,
+            "Game value is incorrect: {value}"
+        );
+        assert_close(&row_strategy, &[0.4, 0.6], "Row strategy is incorrect");
+        assert_close(&col_strategy, &[0.4, 0.6], "Column strategy is incorrect");
+    }
+
+    #[test]
+    fn test_solve_with_dominance() {
+        let a = vec![
+            vec![4.0, 1.0, 3.0],
+            vec![2.0, 3.0, 4.0],
+            vec![3.0, 0.0, 2.0],
+        ];
+
+        let solution = solve(&a);
+
+        assert_eq!(solution.saddle_point, None);
+        assert!((solution.value - 2.5).abs() < 1e-4);
+        assert_close(&solution.row_strategy, &[0.25, 0.75, 0.0], "Row strategy");
+        assert_close(&solution.col_strategy, &[0.5, 0.5, 0.0], "Column strategy");
+    }
+
+    #[test]
+    fn test_brown_robinson() {
+        let a = vec![vec![2.0, -1.0], vec![-1.0, 1.0]];
+
+        let solution = brown_robinson(&a, 1000, 0.01, 15);
+
+        assert!(solution.converged, "Fictitious play must converge.");
+        assert!(
+            solution.lower_bound <= 0.2 && 0.2 <= solution.upper_bound,
+            "Bounds must contain the game value."
+        );
+        assert!(
+            (solution.value - 0.2).abs() < 0.01,
+            "Approximate value is incorrect: {}",
+            solution.value
+        );
+        assert_close(&solution.col_strategy, &[0.4, 0.6], "Column strategy");
+        assert!((solution.row_strategy.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+
+        // Only the first iterations keep accumulated payoffs.
+        let solution = brown_robinson(&a, 1000, 0.01, 5);
+        assert_eq!(solution.iterations.len(), 5);
+        assert!(solution.bounds.len() > 5);
+    }
+
+    #[test]
+    fn test_brown_robinson_tolerance() {
+        let solution = brown_robinson(&[vec![3.0, 5.0], vec![1.0, 2.0]], 1000, 1e-3, 15);
+
+        assert!(
+            solution.converged,
+            "Game with a saddle point converges at once."
+        );
+        assert_eq!(solution.value, 3.0);
+    }
+}
@@ -10,6 +10,11 @@ pub struct InputPanel {
     y_input: ValueInput,
     pub custom_text: String,
     pub risk_condition_checked: bool,
+    pub compare_with_uncertainty_checked: bool,
+    pub game_checked: bool,
+    pub prune_dominated_checked: bool,
+    pub multi_criteria_checked: bool,
+    pub auto_recompute_checked: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -18,6 +23,11 @@ pub enum InputPanelMessage {
     YMessage(ValueInputMessage),
     GenerateButtonPressed,
     RiskConditionChecked(bool),
+    CompareWithUncertaintyChecked(bool),
+    GameChecked(bool),
+    PruneDominatedChecked(bool),
+    MultiCriteriaChecked(bool),
+    AutoRecomputeChecked(bool),
 }
 
 impl InputPanel {
@@ -27,25 +37,24 @@ impl InputPanel {
             y_input: ValueInput::new("Кількість стовпців".to_string()),
             custom_text: "".to_string(),
             risk_condition_checked: false,
+            compare_with_uncertainty_checked: false,
+            game_checked: false,
+            prune_dominated_checked: false,
+            multi_criteria_checked: false,
+            auto_recompute_checked: false,
         }
     }
 
-    pub fn view(&self) -> Element<InputPanelMessage> {
+    pub fn view(&self) -> Element<'_, InputPanelMessage> {
         column![
             row![
-                column![Text::new(format!("Кількість рядків: "))],
-                column![self
-                    .x_input
-                    .view()
-                    .map(move |message| InputPanelMessage::XMessage(message)),],
+                column![Text::new("Кількість рядків: ")],
+                column![self.x_input.view().map(InputPanelMessage::XMessage),],
             ]
             .align_items(iced::Alignment::Center),
             row![
-                column![Text::new(format!("Кількість стовпців: "))],
-                column![self
-                    .y_input
-                    .view()
-                    .map(move |message| InputPanelMessage::YMessage(message))],
+                column![Text::new("Кількість стовпців: ")],
+                column![self.y_input.view().map(InputPanelMessage::YMessage)],
             ]
             .align_items(iced::Alignment::Center),
             checkbox(
@@ -53,6 +62,31 @@ impl InputPanel {
                 self.risk_condition_checked,
                 InputPanelMessage::RiskConditionChecked
             ),
+            checkbox(
+                "Порівняти з критеріями в умовах невизначеності",
+                self.compare_with_uncertainty_checked,
+                InputPanelMessage::CompareWithUncertaintyChecked
+            ),
+            checkbox(
+                "Розв'язати як матричну гру",
+                self.game_checked,
+                InputPanelMessage::GameChecked
+            ),
+            checkbox(
+                "Відкинути доміновані альтернативи",
+                self.prune_dominated_checked,
+                InputPanelMessage::PruneDominatedChecked
+            ),
+            checkbox(
+                "Багатокритеріальний аналіз (стовпці — атрибути, p — ваги)",
+                self.multi_criteria_checked,
+                InputPanelMessage::MultiCriteriaChecked
+            ),
+            checkbox(
+                "Автоматично перераховувати критерії",
+                self.auto_recompute_checked,
+                InputPanelMessage::AutoRecomputeChecked
+            ),
             button("Генерувати").on_press(InputPanelMessage::GenerateButtonPressed),
             Text::new(&self.custom_text)
         ]
@@ -62,6 +96,20 @@ impl InputPanel {
         .into()
     }
 
+    /// The `p` row holds probabilities under risk and weights of attributes
+    /// in the multi-criteria mode.
+    pub fn shows_p_row(&self) -> bool {
+        self.risk_condition_checked || self.multi_criteria_checked
+    }
+
+    pub fn p_label(&self) -> &'static str {
+        if self.multi_criteria_checked {
+            "w"
+        } else {
+            "p"
+        }
+    }
+
     pub fn update_x_input(&mut self, value: String) {
         self.x_input.value = value;
     }
@@ -74,12 +122,12 @@ impl InputPanel {
         let parse_positive_integer = |x: &String| -> Result<usize, &str> {
             if let Ok(x) = x.parse::<usize>() {
                 if x > 0 {
-                    return Ok(x);
+                    Ok(x)
                 } else {
-                    return Err("Not positive integer");
+                    Err("Not positive integer")
                 }
             } else {
-                return Err("Not integer");
+                Err("Not integer")
             }
         };
 
@@ -0,0 +1,214 @@
+use std::fmt::Display;
+
+use crate::{
+    answer_block::profits_losses_radio::Choise,
+    criterion::{
+        get_best,
+        risk_condition::bayes_scores,
+        uncertainty::{hurwitz_scores, maximax_scores, minimax_scores, savage_scores},
+        Payoff,
+    },
+    utils::parse_list,
+};
+
+/// Closed interval of possible payoffs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Interval {
+    pub lower: f32,
+    pub upper: f32,
+}
+
+impl Interval {
+    pub fn new(lower: f32, upper: f32) -> Self {
+        Interval { lower, upper }
+    }
+
+    /// Parses a number or an interval written as `[a; b]` with `a ≤ b`.
+    pub fn parse(cell: &str) -> Result<Self, &'static str> {
+        let cell = cell.trim();
+
+        if let Ok(value) = cell.parse::<f32>() {
+            return Ok(Interval::point(value));
+        }
+
+        let bounds = cell
+            .strip_prefix('[')
+            .and_then(|cell| cell.strip_suffix(']'))
+            .ok_or("Interval is invalid.")?;
+
+        match parse_list(bounds).map_err(|_| "Interval is invalid.")?[..] {
+            [lower, upper] if lower <= upper => Ok(Interval::new(lower, upper)),
+            _ => Err("Interval is invalid."),
+        }
+    }
+
+    pub fn midpoint(&self) -> f32 {
+        (self.lower + self.upper) / 2.0
+    }
+
+    pub fn is_point(&self) -> bool {
+        self.lower == self.upper
+    }
+}
+
+impl Payoff for Interval {
+    fn point(value: f32) -> Self {
+        Interval::new(value, value)
+    }
+
+    /// Interval of `min(x, y)` over all values of `x` and `y`.
+    fn min(self, other: Interval) -> Interval {
+        Interval::new(self.lower.min(other.lower), self.upper.min(other.upper))
+    }
+
+    fn max(self, other: Interval) -> Interval {
+        Interval::new(self.lower.max(other.lower), self.upper.max(other.upper))
+    }
+
+    fn add(self, other: Interval) -> Interval {
+        Interval::new(self.lower + other.lower, self.upper + other.upper)
+    }
+
+    fn sub(self, other: Interval) -> Interval {
+        Interval::new(self.lower - other.upper, self.upper - other.lower)
+    }
+
+    fn scale(self, k: f32) -> Interval {
+        Interval::new(k * self.lower, k * self.upper)
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{:.4}; {:.4}]", self.lower, self.upper)
+    }
+}
+
+/// Interval scores of alternatives under a criterion.
+pub struct IntervalDecision {
+    pub scores: Vec<Interval>,
+    /// Alternatives with the best midpoint of the score.
+    pub best: Vec<usize>,
+    /// The best alternative stays optimal for any values in the intervals:
+    /// its worst score is not worse than the best score of any other one.
+    pub robust: bool,
+}
+
+impl IntervalDecision {
+    fn new(scores: Vec<Interval>, minimize: bool) -> Self {
+        let midpoints: Vec<f32> = scores.iter().map(Interval::midpoint).collect();
+        let best = get_best(&midpoints, minimize).1;
+
+        let robust = best.len() == 1
+            && (0..scores.len()).filter(|&row| row != best[0]).all(|row| {
+                if minimize {
+                    scores[best[0]].upper <= scores[row].lower
+                } else {
+                    scores[best[0]].lower >= scores[row].upper
+                }
+            });
+
+        IntervalDecision {
+            scores,
+            best,
+            robust,
+        }
+    }
+}
+
+pub fn wald(a: &[Vec<Interval>], profits_losses: Choise) -> IntervalDecision {
+    IntervalDecision::new(
+        minimax_scores(a, profits_losses),
+        profits_losses == Choise::Losses,
+    )
+}
+
+pub fn maximax(a: &[Vec<Interval>]) -> IntervalDecision {
+    IntervalDecision::new(maximax_scores(a), false)
+}
+
+pub fn hurwitz(a: &[Vec<Interval>], alpha: f32) -> IntervalDecision {
+    IntervalDecision::new(hurwitz_scores(a, alpha), false)
+}
+
+/// Maximal regrets. Bounds of every regret are exact, but regrets of
+/// different alternatives share cells, so the robustness check is only
+/// sufficient.
+pub fn savage(a: &[Vec<Interval>]) -> IntervalDecision {
+    IntervalDecision::new(savage_scores(a), true)
+}
+
+pub fn bayes(a: &[Vec<Interval>], p: &[f32]) -> IntervalDecision {
+    IntervalDecision::new(bayes_scores(a, p), false)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answer_block::profits_losses_radio::Choise,
+        criterion::Payoff,
+        interval::{bayes, hurwitz, maximax, savage, wald, Interval},
+    };
+
+    fn generate_test_data() -> Vec<Vec<Interval>> {
+        vec![
+            vec![Interval::new(80.0, 120.0), Interval::point(50.0)],
+            vec![Interval::point(60.0), Interval::new(55.0, 65.0)],
+        ]
+    }
+
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!(
+            Interval::parse(" [80; 120] "),
+            Ok(Interval::new(80.0, 120.0))
+        );
+        assert_eq!(Interval::parse("5"), Ok(Interval::point(5.0)));
+        assert!(Interval::parse("[120; 80]").is_err());
+        assert!(Interval::parse("[1; 2; 3]").is_err());
+        assert!(Interval::parse("(1; 2)").is_err());
+    }
+
+    #[test]
+    fn test_interval_criteria() {
+        let a = generate_test_data();
+
+        let decision = wald(&a, Choise::Profits);
+        assert_eq!(decision.scores[0], Interval::new(50.0, 50.0));
+        assert_eq!(decision.scores[1], Interval::new(55.0, 60.0));
+        assert_eq!(decision.best, vec![1]);
+        assert!(decision.robust);
+
+        // Losses are judged by the largest one.
+        let decision = wald(&a, Choise::Losses);
+        assert_eq!(decision.scores[0], Interval::new(80.0, 120.0));
+        assert_eq!(decision.scores[1], Interval::new(60.0, 65.0));
+        assert_eq!(decision.best, vec![1]);
+        assert!(decision.robust);
+
+        let decision = maximax(&a);
+        assert_eq!(decision.scores[0], Interval::new(80.0, 120.0));
+        assert_eq!(decision.best, vec![0]);
+        assert!(decision.robust);
+
+        let decision = bayes(&a, &[0.5, 0.5]);
+        assert_eq!(decision.scores[0], Interval::new(65.0, 85.0));
+        assert_eq!(decision.scores[1], Interval::new(57.5, 62.5));
+        assert!(decision.robust);
+
+        // Regret of the first alternative in the second state is 5..15.
+        let decision = savage(&a);
+        assert_eq!(decision.scores[0], Interval::new(5.0, 15.0));
+        assert_eq!(decision.scores[1], Interval::new(20.0, 60.0));
+        assert_eq!(decision.best, vec![0]);
+        assert!(decision.robust);
+
+        let decision = hurwitz(
+            &[vec![Interval::new(0.0, 100.0)], vec![Interval::point(40.0)]],
+            0.5,
+        );
+        // The midpoint 50 beats the sure 40, but values below 40 are possible.
+        assert_eq!(decision.best, vec![0]);
+        assert!(!decision.robust);
+    }
+}
@@ -1,13 +1,25 @@
 use iced::{Application, Settings};
 
+mod ahp;
 mod answer_block;
 mod app;
 mod constants;
 mod criterion;
+mod decision_tree;
+mod fuzzy;
+mod game_theory;
 mod input_panel;
+mod interval;
+mod monte_carlo;
+mod multi_criteria;
+mod outranking;
+mod prospect_theory;
+mod risk_measures;
+mod stochastic_dominance;
 mod table;
-mod value_component;
+mod utility;
 mod utils;
+mod value_component;
 
 pub fn main() -> iced::Result {
     app::Criteria::run(Settings::default())
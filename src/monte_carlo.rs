@@ -0,0 +1,429 @@
+use std::{f32::consts::PI, fmt::Display};
+
+use crate::{
+    answer_block::profits_losses_radio::Choise,
+    criterion::{
+        get_best,
+        risk_condition::{bayes_scores, dispersion_scores, modal_scores, modal_state},
+        uncertainty::{hurwitz_scores, maximax_scores, minimax_scores, savage_scores},
+    },
+    utils::parse_list,
+};
+
+/// SplitMix64 generator, enough for simulations and reproducible by seed.
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Random { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        z ^ (z >> 31)
+    }
+
+    /// Uniform number from `[0, 1)`.
+    pub fn uniform(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Standard normal number by the Box–Muller transform.
+    pub fn normal(&mut self) -> f32 {
+        let u = 1.0 - self.uniform();
+        let v = self.uniform();
+
+        (-2.0 * u.ln()).sqrt() * (2.0 * PI * v).cos()
+    }
+
+    /// Gamma distributed number with `shape ≥ 1` (Marsaglia and Tsang).
+    fn gamma(&mut self, shape: f32) -> f32 {
+        let d = shape - 1.0 / 3.0;
+        let c = 1.0 / (9.0 * d).sqrt();
+
+        loop {
+            let x = self.normal();
+            let v = (1.0 + c * x).powi(3);
+
+            if v > 0.0 && (1.0 - self.uniform()).ln() < 0.5 * x * x + d - d * v + d * v.ln() {
+                return d * v;
+            }
+        }
+    }
+
+    fn beta(&mut self, alpha: f32, beta: f32) -> f32 {
+        let x = self.gamma(alpha);
+        let y = self.gamma(beta);
+
+        x / (x + y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Distribution {
+    Constant(f32),
+    /// `U(a; b)`.
+    Uniform(f32, f32),
+    /// `T(a; m; b)` with mode `m`.
+    Triangular(f32, f32, f32),
+    /// `N(μ; σ)`.
+    Normal(f32, f32),
+    /// `PERT(a; m; b)`, a beta distribution with mode `m`.
+    Pert(f32, f32, f32),
+}
+
+impl Distribution {
+    /// Parses a number or a distribution written as `U(a; b)`, `T(a; m; b)`,
+    /// `N(μ; σ)` or `PERT(a; m; b)`.
+    pub fn parse(cell: &str) -> Result<Self, &'static str> {
+        let cell = cell.trim();
+
+        if let Ok(value) = cell.parse::<f32>() {
+            return Ok(Distribution::Constant(value));
+        }
+
+        let (name, arguments) = cell
+            .strip_suffix(')')
+            .and_then(|cell| cell.split_once('('))
+            .ok_or("Distribution is invalid.")?;
+        let arguments = parse_list(arguments).map_err(|_| "Distribution is invalid.")?;
+
+        let distribution = match (name.trim().to_uppercase().as_str(), &arguments[..]) {
+            ("U", &[a, b]) => Distribution::Uniform(a, b),
+            ("T", &[a, m, b]) => Distribution::Triangular(a, m, b),
+            ("N", &[mean, deviation]) => Distribution::Normal(mean, deviation),
+            ("PERT", &[a, m, b]) => Distribution::Pert(a, m, b),
+            _ => return Err("Distribution is invalid."),
+        };
+
+        let is_valid = match distribution {
+            Distribution::Uniform(a, b) => a <= b,
+            Distribution::Triangular(a, m, b) | Distribution::Pert(a, m, b) => a <= m && m <= b,
+            Distribution::Normal(_, deviation) => deviation >= 0.0,
+            Distribution::Constant(_) => true,
+        };
+
+        if is_valid {
+            Ok(distribution)
+        } else {
+            Err("Parameters of distribution are invalid.")
+        }
+    }
+
+    pub fn mean(&self) -> f32 {
+        match *self {
+            Distribution::Constant(value) => value,
+            Distribution::Uniform(a, b) => (a + b) / 2.0,
+            Distribution::Triangular(a, m, b) => (a + m + b) / 3.0,
+            Distribution::Normal(mean, _) => mean,
+            Distribution::Pert(a, m, b) => (a + 4.0 * m + b) / 6.0,
+        }
+    }
+
+    pub fn is_constant(&self) -> bool {
+        matches!(self, Distribution::Constant(_))
+    }
+
+    pub fn sample(&self, random: &mut Random) -> f32 {
+        match *self {
+            Distribution::Constant(value) => value,
+            Distribution::Uniform(a, b) => a + (b - a) * random.uniform(),
+            Distribution::Triangular(a, m, b) => {
+                if a == b {
+                    return a;
+                }
+
+                let u = random.uniform();
+                if u < (m - a) / (b - a) {
+                    a + (u * (b - a) * (m - a)).sqrt()
+                } else {
+                    b - ((1.0 - u) * (b - a) * (b - m)).sqrt()
+                }
+            }
+            Distribution::Normal(mean, deviation) => mean + deviation * random.normal(),
+            Distribution::Pert(a, m, b) => {
+                if a == b {
+                    return a;
+                }
+
+                let alpha = 1.0 + 4.0 * (m - a) / (b - a);
+                let beta = 1.0 + 4.0 * (b - m) / (b - a);
+
+                a + (b - a) * random.beta(alpha, beta)
+            }
+        }
+    }
+}
+
+/// Criteria evaluated on every sampled matrix. Only Wald follows the
+/// minimax direction, the other criteria treat payoffs as profits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimulatedCriterion {
+    Wald,
+    Maximax,
+    Hurwitz,
+    Savage,
+    Bayes,
+    DispersionMinimization,
+    Modal,
+}
+
+impl SimulatedCriterion {
+    pub const ALL: [SimulatedCriterion; 7] = [
+        SimulatedCriterion::Wald,
+        SimulatedCriterion::Maximax,
+        SimulatedCriterion::Hurwitz,
+        SimulatedCriterion::Savage,
+        SimulatedCriterion::Bayes,
+        SimulatedCriterion::DispersionMinimization,
+        SimulatedCriterion::Modal,
+    ];
+
+    /// Criteria under risk need `p`, the modal one also a single most
+    /// probable state.
+    pub fn is_defined(&self, p: Option<&[f32]>) -> bool {
+        match self {
+            SimulatedCriterion::Bayes | SimulatedCriterion::DispersionMinimization => p.is_some(),
+            SimulatedCriterion::Modal => p.and_then(modal_state).is_some(),
+            _ => true,
+        }
+    }
+
+    /// Score of every alternative, `alpha` is the Hurwitz coefficient.
+    pub fn scores(
+        &self,
+        a: &[Vec<f32>],
+        p: &[f32],
+        alpha: f32,
+        profits_losses: Choise,
+    ) -> Vec<f32> {
+        match self {
+            SimulatedCriterion::Wald => minimax_scores(a, profits_losses),
+            SimulatedCriterion::Maximax => maximax_scores(a),
+            SimulatedCriterion::Hurwitz => hurwitz_scores(a, alpha),
+            SimulatedCriterion::Savage => savage_scores(a),
+            SimulatedCriterion::Bayes => bayes_scores(a, p),
+            SimulatedCriterion::DispersionMinimization => dispersion_scores(a, p),
+            SimulatedCriterion::Modal => {
+                modal_scores(a, p).expect("Modal criterion needs a single most probable state.")
+            }
+        }
+    }
+
+    pub fn best(&self, scores: &[f32], profits_losses: Choise) -> Vec<usize> {
+        let minimize = match self {
+            SimulatedCriterion::Wald => profits_losses == Choise::Losses,
+            SimulatedCriterion::Savage | SimulatedCriterion::DispersionMinimization => true,
+            _ => false,
+        };
+
+        get_best(scores, minimize).1
+    }
+}
+
+impl Display for SimulatedCriterion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                SimulatedCriterion::Wald => "Вальда",
+                SimulatedCriterion::Maximax => "Максимакс",
+                SimulatedCriterion::Hurwitz => "Гурвіца",
+                SimulatedCriterion::Savage => "Севіджа",
+                SimulatedCriterion::Bayes => "Байєса",
+                SimulatedCriterion::DispersionMinimization => "Мінімізація дисперсії",
+                SimulatedCriterion::Modal => "Модальний",
+            }
+        )
+    }
+}
+
+pub struct CriterionSimulation {
+    pub criterion: SimulatedCriterion,
+    /// Share of samples in which an alternative is chosen, ties are split
+    /// equally so the shares sum up to 1.
+    pub win_frequencies: Vec<f32>,
+    /// Sorted scores of every alternative over all samples.
+    pub scores: Vec<Vec<f32>>,
+}
+
+impl CriterionSimulation {
+    pub fn mean(&self, row: usize) -> f32 {
+        self.scores[row].iter().sum::<f32>() / self.scores[row].len() as f32
+    }
+
+    pub fn standard_deviation(&self, row: usize) -> f32 {
+        let mean = self.mean(row);
+
+        (self.scores[row]
+            .iter()
+            .map(|score| (score - mean).powi(2))
+            .sum::<f32>()
+            / self.scores[row].len() as f32)
+            .sqrt()
+    }
+
+    /// Empirical quantile of scores of an alternative.
+    pub fn quantile(&self, row: usize, level: f32) -> f32 {
+        let scores = &self.scores[row];
+        let index = ((scores.len() - 1) as f32 * level).round() as usize;
+
+        scores[index]
+    }
+}
+
+/// Samples `samples` matrices from `cells` and evaluates every defined
+/// criterion on each of them.
+pub fn simulate(
+    cells: &[Vec<Distribution>],
+    p: Option<&[f32]>,
+    alpha: f32,
+    profits_losses: Choise,
+    samples: usize,
+    seed: u64,
+) -> Vec<CriterionSimulation> {
+    let criteria: Vec<SimulatedCriterion> = SimulatedCriterion::ALL
+        .into_iter()
+        .filter(|criterion| criterion.is_defined(p))
+        .collect();
+
+    let mut simulations: Vec<CriterionSimulation> = criteria
+        .iter()
+        .map(|&criterion| CriterionSimulation {
+            criterion,
+            win_frequencies: vec![0.0; cells.len()],
+            scores: vec![Vec::with_capacity(samples); cells.len()],
+        })
+        .collect();
+
+    let mut random = Random::new(seed);
+
+    for _ in 0..samples {
+        let a: Vec<Vec<f32>> = cells
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.sample(&mut random)).collect())
+            .collect();
+
+        for simulation in simulations.iter_mut() {
+            let scores =
+                simulation
+                    .criterion
+                    .scores(&a, p.unwrap_or_default(), alpha, profits_losses);
+            let best = simulation.criterion.best(&scores, profits_losses);
+
+            for &row in best.iter() {
+                simulation.win_frequencies[row] += 1.0 / (best.len() * samples) as f32;
+            }
+            for (row, score) in scores.into_iter().enumerate() {
+                simulation.scores[row].push(score);
+            }
+        }
+    }
+
+    for simulation in simulations.iter_mut() {
+        for scores in simulation.scores.iter_mut() {
+            scores.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        }
+    }
+
+    simulations
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answer_block::profits_losses_radio::Choise,
+        monte_carlo::{simulate, Distribution, Random, SimulatedCriterion},
+    };
+
+    #[test]
+    fn test_parse_distribution() {
+        assert_eq!(Distribution::parse("5"), Ok(Distribution::Constant(5.0)));
+        assert_eq!(
+            Distribution::parse(" u(1; 3) "),
+            Ok(Distribution::Uniform(1.0, 3.0))
+        );
+        assert_eq!(
+            Distribution::parse("PERT(0; 1; 5)"),
+            Ok(Distribution::Pert(0.0, 1.0, 5.0))
+        );
+        assert!(Distribution::parse("T(3; 1; 2)").is_err());
+        assert!(Distribution::parse("N(1)").is_err());
+        assert!(Distribution::parse("X(1; 2)").is_err());
+    }
+
+    #[test]
+    fn test_sample_means() {
+        let mut random = Random::new(7);
+
+        for distribution in [
+            Distribution::Uniform(0.0, 10.0),
+            Distribution::Triangular(0.0, 2.0, 10.0),
+            Distribution::Normal(5.0, 2.0),
+            Distribution::Pert(0.0, 2.0, 10.0),
+        ] {
+            let samples = 20_000;
+            let mean = (0..samples)
+                .map(|_| distribution.sample(&mut random))
+                .sum::<f32>()
+                / samples as f32;
+
+            assert!(
+                (mean - distribution.mean()).abs() < 0.1,
+                "{distribution:?} gives mean {mean}."
+            );
+        }
+    }
+
+    #[test]
+    fn test_simulate() {
+        let cells = vec![
+            vec![
+                Distribution::Uniform(0.0, 10.0),
+                Distribution::Constant(5.0),
+            ],
+            vec![Distribution::Constant(4.0), Distribution::Constant(4.0)],
+        ];
+
+        let simulations = simulate(&cells, None, 0.5, Choise::Profits, 1000, 1);
+        let again = simulate(&cells, None, 0.5, Choise::Profits, 1000, 1);
+
+        assert_eq!(
+            simulations.len(),
+            4,
+            "Criteria under risk require probabilities."
+        );
+        assert_eq!(
+            simulations[0].scores, again[0].scores,
+            "Seed must be respected."
+        );
+
+        // Wald chooses the first alternative when the uniform cell is above 4.
+        let wald = &simulations[0];
+        assert!((wald.win_frequencies[0] - 0.6).abs() < 0.05);
+        assert!((wald.win_frequencies.iter().sum::<f32>() - 1.0).abs() < 1e-3);
+        assert_eq!(wald.quantile(1, 0.5), 4.0);
+
+        // As losses the largest value of the first alternative is at least 5.
+        let simulations = simulate(&cells, Some(&[0.5, 0.5]), 0.5, Choise::Losses, 100, 1);
+        assert_eq!(simulations[0].win_frequencies[0], 0.0);
+        assert!((simulations[0].win_frequencies[1] - 1.0).abs() < 1e-4);
+        assert!(
+            simulations
+                .iter()
+                .all(|simulation| simulation.criterion != SimulatedCriterion::Modal),
+            "Modal criterion requires a single most probable state."
+        );
+
+        let simulations = simulate(&cells, Some(&[0.6, 0.4]), 0.5, Choise::Profits, 100, 1);
+        assert_eq!(simulations.len(), SimulatedCriterion::ALL.len());
+    }
+}
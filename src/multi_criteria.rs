@@ -0,0 +1,422 @@
+use std::fmt::Display;
+
+use crate::{
+    answer_block::profits_losses_radio::Choise,
+    criterion::{dominance::non_dominated, get_indeces, get_max, get_min},
+};
+
+/// Whether larger (benefit) or smaller (cost) values of an attribute are better.
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Benefit,
+    Cost,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 2] = [Direction::Benefit, Direction::Cost];
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Direction::Benefit => "Максимізувати",
+                Direction::Cost => "Мінімізувати",
+            }
+        )
+    }
+}
+
+pub fn column(a: &[Vec<f32>], col: usize) -> Vec<f32> {
+    a.iter().map(|row| row[col]).collect()
+}
+
+/// Alternatives not dominated by any other one over all attributes.
+pub fn pareto_front(a: &[Vec<f32>], directions: &[Direction]) -> Vec<usize> {
+    // Cost attributes are negated, so that every attribute is maximized.
+    let benefits: Vec<Vec<f32>> = a
+        .iter()
+        .map(|row| {
+            row.iter()
+                .zip(directions.iter())
+                .map(|(&value, direction)| match direction {
+                    Direction::Benefit => value,
+                    Direction::Cost => -value,
+                })
+                .collect()
+        })
+        .collect();
+
+    non_dominated(&benefits, Choise::Profits)
+}
+
+/// Linear min–max normalization into `[0, 1]`, where 1 is the best value of
+/// an attribute. Attributes with equal values are normalized to 1.
+pub fn normalize(a: &[Vec<f32>], directions: &[Direction]) -> Vec<Vec<f32>> {
+    let ranges: Vec<(f32, f32)> = (0..directions.len())
+        .map(|col| {
+            let values = column(a, col);
+            (get_min(&values), get_max(&values))
+        })
+        .collect();
+
+    a.iter()
+        .map(|row| {
+            row.iter()
+                .zip(ranges.iter().zip(directions.iter()))
+                .map(|(&value, (&(min, max), direction))| {
+                    if max == min {
+                        1.0
+                    } else {
+                        match direction {
+                            Direction::Benefit => (value - min) / (max - min),
+                            Direction::Cost => (max - value) / (max - min),
+                        }
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Simple additive weighting scores of every alternative.
+pub fn saw_scores(a: &[Vec<f32>], weights: &[f32], directions: &[Direction]) -> Vec<f32> {
+    normalize(a, directions)
+        .iter()
+        .map(|row| row.iter().zip(weights.iter()).map(|(r, w)| r * w).sum())
+        .collect()
+}
+
+pub fn saw(a: &[Vec<f32>], weights: &[f32], directions: &[Direction]) -> (f32, Vec<usize>) {
+    best(&saw_scores(a, weights, directions))
+}
+
+/// The best score and alternatives reaching it.
+pub fn best(scores: &[f32]) -> (f32, Vec<usize>) {
+    let best_score = get_max(scores);
+
+    (best_score, get_indeces(scores, best_score))
+}
+
+/// Alternatives ordered from the best score to the worst.
+pub fn rank(scores: &[f32]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..scores.len()).collect();
+    order.sort_by(|&x, &y| scores[y].total_cmp(&scores[x]));
+
+    order
+}
+
+/// Intermediate matrices of TOPSIS.
+#[derive(Default)]
+pub struct TopsisTrace {
+    pub normalized: Vec<Vec<f32>>,
+    pub weighted: Vec<Vec<f32>>,
+    pub ideal: Vec<f32>,
+    pub anti_ideal: Vec<f32>,
+    pub ideal_distances: Vec<f32>,
+    pub anti_ideal_distances: Vec<f32>,
+    /// Relative closeness to the ideal solution, the larger the better.
+    pub closeness: Vec<f32>,
+}
+
+pub fn topsis(a: &[Vec<f32>], weights: &[f32], directions: &[Direction]) -> TopsisTrace {
+    let norms: Vec<f32> = (0..directions.len())
+        .map(|col| column(a, col).iter().map(|x| x * x).sum::<f32>().sqrt())
+        .collect();
+
+    let normalized: Vec<Vec<f32>> = a
+        .iter()
+        .map(|row| {
+            row.iter()
+                .zip(norms.iter())
+                .map(|(x, &norm)| if norm > 0.0 { x / norm } else { 0.0 })
+                .collect()
+        })
+        .collect();
+
+    let weighted: Vec<Vec<f32>> = normalized
+        .iter()
+        .map(|row| row.iter().zip(weights.iter()).map(|(r, w)| r * w).collect())
+        .collect();
+
+    let (ideal, anti_ideal): (Vec<f32>, Vec<f32>) = directions
+        .iter()
+        .enumerate()
+        .map(|(col, direction)| {
+            let values = column(&weighted, col);
+            match direction {
+                Direction::Benefit => (get_max(&values), get_min(&values)),
+                Direction::Cost => (get_min(&values), get_max(&values)),
+            }
+        })
+        .unzip();
+
+    let distances = |target: &[f32]| -> Vec<f32> {
+        weighted
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(target.iter())
+                    .map(|(v, t)| (v - t).powi(2))
+                    .sum::<f32>()
+                    .sqrt()
+            })
+            .collect()
+    };
+
+    let ideal_distances = distances(&ideal);
+    let anti_ideal_distances = distances(&anti_ideal);
+
+    // Equal alternatives are at zero distance from both solutions.
+    let closeness = ideal_distances
+        .iter()
+        .zip(anti_ideal_distances.iter())
+        .map(|(d_plus, d_minus)| {
+            if d_plus + d_minus > 0.0 {
+                d_minus / (d_plus + d_minus)
+            } else {
+                1.0
+            }
+        })
+        .collect();
+
+    TopsisTrace {
+        normalized,
+        weighted,
+        ideal,
+        anti_ideal,
+        ideal_distances,
+        anti_ideal_distances,
+        closeness,
+    }
+}
+
+#[derive(Default)]
+pub struct VikorResult {
+    /// Group utility.
+    pub s: Vec<f32>,
+    /// Individual regret.
+    pub r: Vec<f32>,
+    /// Compromise index, the smaller the better.
+    pub q: Vec<f32>,
+    /// Alternatives ordered by `q`.
+    pub order: Vec<usize>,
+    pub acceptable_advantage: bool,
+    pub acceptable_stability: bool,
+    /// Recommended compromise solutions.
+    pub compromise: Vec<usize>,
+}
+
+/// VIKOR compromise ranking with weight `v` of the strategy of the majority.
+/// Empty result for a matrix without alternatives.
+pub fn vikor(a: &[Vec<f32>], weights: &[f32], directions: &[Direction], v: f32) -> VikorResult {
+    if a.is_empty() {
+        return VikorResult::default();
+    }
+
+    // Weighted distances from the best values, 0 for the best, w_j for the worst.
+    let distances: Vec<Vec<f32>> = normalize(a, directions)
+        .iter()
+        .map(|row| {
+            row.iter()
+                .zip(weights.iter())
+                .map(|(r, w)| w * (1.0 - r))
+                .collect()
+        })
+        .collect();
+
+    let s: Vec<f32> = distances.iter().map(|row| row.iter().sum()).collect();
+    let r: Vec<f32> = distances.iter().map(|row| get_max(row)).collect();
+
+    let scaled = |values: &[f32], value: f32| {
+        let (min, max) = (get_min(values), get_max(values));
+        if max > min {
+            (value - min) / (max - min)
+        } else {
+            0.0
+        }
+    };
+
+    let q: Vec<f32> = s
+        .iter()
+        .zip(r.iter())
+        .map(|(&s_value, &r_value)| v * scaled(&s, s_value) + (1.0 - v) * scaled(&r, r_value))
+        .collect();
+
+    let mut order: Vec<usize> = (0..q.len()).collect();
+    order.sort_by(|&x, &y| q[x].total_cmp(&q[y]));
+
+    let first = order[0];
+    let threshold = 1.0 / (q.len().max(2) - 1) as f32;
+
+    let acceptable_advantage = order
+        .get(1)
+        .is_none_or(|&second| q[second] - q[first] >= threshold - 1e-6);
+    let acceptable_stability = s[first] == get_min(&s) || r[first] == get_min(&r);
+
+    let compromise = if !acceptable_advantage {
+        order
+            .iter()
+            .copied()
+            .take_while(|&alternative| q[alternative] - q[first] < threshold - 1e-6)
+            .collect()
+    } else if !acceptable_stability {
+        order[..2].to_vec()
+    } else {
+        vec![first]
+    };
+
+    VikorResult {
+        s,
+        r,
+        q,
+        order,
+        acceptable_advantage,
+        acceptable_stability,
+        compromise,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        criterion::get_min,
+        multi_criteria::{
+            column, normalize, pareto_front, rank, saw, saw_scores, topsis, vikor, Direction,
+        },
+    };
+
+    fn generate_test_data() -> (Vec<Vec<f32>>, Vec<f32>, Vec<Direction>) {
+        // Price, quality and delivery time of four suppliers.
+        (
+            vec![
+                vec![100.0, 8.0, 5.0],
+                vec![80.0, 6.0, 7.0],
+                vec![120.0, 9.0, 3.0],
+                vec![110.0, 7.0, 6.0],
+            ],
+            vec![0.5, 0.3, 0.2],
+            vec![Direction::Cost, Direction::Benefit, Direction::Cost],
+        )
+    }
+
+    #[test]
+    fn test_pareto_front() {
+        let (a, _, directions) = generate_test_data();
+
+        assert_eq!(
+            pareto_front(&a, &directions),
+            vec![0, 1, 2],
+            "Only the fourth supplier is dominated (by the first)."
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        let (a, _, directions) = generate_test_data();
+
+        let normalized = normalize(&a, &directions);
+
+        assert_eq!(normalized[1][0], 1.0);
+        assert_eq!(normalized[2][0], 0.0);
+        assert_eq!(normalized[2][1], 1.0);
+        assert_eq!(normalized[0][2], 0.5);
+    }
+
+    #[test]
+    fn test_saw() {
+        let (a, weights, directions) = generate_test_data();
+
+        let scores = saw_scores(&a, &weights, &directions);
+        let expected = [0.55, 0.5, 0.5, 0.275];
+
+        for (score, expected) in scores.iter().zip(expected.iter()) {
+            assert!(
+                (score - expected).abs() < 1e-5,
+                "SAW scores are incorrect: {scores:?}"
+            );
+        }
+
+        assert_eq!(saw(&a, &weights, &directions).1, vec![0]);
+    }
+
+    #[test]
+    fn test_topsis() {
+        let (a, weights, directions) = generate_test_data();
+
+        let trace = topsis(&a, &weights, &directions);
+        let expected = [0.53166, 0.50582, 0.49418, 0.26739];
+
+        for (closeness, expected) in trace.closeness.iter().zip(expected.iter()) {
+            assert!(
+                (closeness - expected).abs() < 1e-4,
+                "TOPSIS closeness is incorrect: {:?}",
+                trace.closeness
+            );
+        }
+
+        assert_eq!(trace.ideal[0], get_min(&column(&trace.weighted, 0)));
+        assert_eq!(rank(&trace.closeness), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_topsis_equal_alternatives() {
+        let a = vec![vec![1.0, 2.0], vec![1.0, 2.0]];
+
+        let trace = topsis(&a, &[0.5, 0.5], &[Direction::Benefit, Direction::Cost]);
+
+        assert_eq!(trace.closeness, vec![1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_vikor() {
+        let (a, weights, directions) = generate_test_data();
+
+        let result = vikor(&a, &weights, &directions, 0.5);
+
+        assert!(
+            (result.s[0] - 0.45).abs() < 1e-5,
+            "S is incorrect: {:?}",
+            result.s
+        );
+        assert!(
+            (result.r[1] - 0.3).abs() < 1e-5,
+            "R is incorrect: {:?}",
+            result.r
+        );
+        assert_eq!(result.order[0], 0);
+        assert!(result.acceptable_stability);
+        assert!(
+            !result.acceptable_advantage,
+            "Q of the second alternative is too close: {:?}",
+            result.q
+        );
+        assert_eq!(result.compromise[0], 0);
+        assert!(result.compromise.len() > 1);
+    }
+
+    #[test]
+    fn test_vikor_empty() {
+        let result = vikor(&[], &[1.0], &[Direction::Benefit], 0.5);
+
+        assert!(result.order.is_empty());
+        assert!(result.compromise.is_empty());
+    }
+
+    #[test]
+    fn test_vikor_single_compromise() {
+        let a = vec![vec![10.0, 10.0], vec![5.0, 4.0], vec![1.0, 2.0]];
+
+        let result = vikor(
+            &a,
+            &[0.5, 0.5],
+            &[Direction::Benefit, Direction::Benefit],
+            0.5,
+        );
+
+        assert_eq!(result.compromise, vec![0]);
+        assert!(result.acceptable_advantage && result.acceptable_stability);
+    }
+}
@@ -0,0 +1,345 @@
+use crate::multi_criteria::{column, Direction};
+
+/// Signed advantage of `x` over `y` on every attribute, positive when `x` is
+/// better.
+fn advantages(x: &[f32], y: &[f32], directions: &[Direction]) -> Vec<f32> {
+    x.iter()
+        .zip(y.iter())
+        .zip(directions.iter())
+        .map(|((x, y), direction)| match direction {
+            Direction::Benefit => x - y,
+            Direction::Cost => y - x,
+        })
+        .collect()
+}
+
+pub mod electre {
+    use crate::criterion::{get_max, get_min};
+
+    use super::{advantages, column, Direction};
+
+    pub struct ElectreResult {
+        pub concordance: Vec<Vec<f32>>,
+        pub discordance: Vec<Vec<f32>>,
+        /// `outranks[x][y]` when `x` outranks `y`.
+        pub outranks: Vec<Vec<bool>>,
+        /// `None` when the outranking graph has cycles.
+        pub kernel: Option<Vec<usize>>,
+    }
+
+    /// ELECTRE I: `x` outranks `y` when the concordance index reaches
+    /// `concordance_threshold` and the discordance index doesn't exceed
+    /// `discordance_threshold`.
+    pub fn electre(
+        a: &[Vec<f32>],
+        weights: &[f32],
+        directions: &[Direction],
+        concordance_threshold: f32,
+        discordance_threshold: f32,
+    ) -> ElectreResult {
+        let n = a.len();
+        let spans: Vec<f32> = (0..directions.len())
+            .map(|col| {
+                let values = column(a, col);
+                get_max(&values) - get_min(&values)
+            })
+            .collect();
+
+        let mut concordance = vec![vec![0.0; n]; n];
+        let mut discordance = vec![vec![0.0; n]; n];
+        let mut outranks = vec![vec![false; n]; n];
+
+        for x in 0..n {
+            for y in (0..n).filter(|&y| y != x) {
+                let advantages = advantages(&a[x], &a[y], directions);
+
+                concordance[x][y] = advantages
+                    .iter()
+                    .zip(weights.iter())
+                    .filter(|(advantage, _)| **advantage >= 0.0)
+                    .map(|(_, weight)| weight)
+                    .sum();
+
+                discordance[x][y] = advantages
+                    .iter()
+                    .zip(spans.iter())
+                    .filter(|(&advantage, &span)| advantage < 0.0 && span > 0.0)
+                    .map(|(advantage, span)| -advantage / span)
+                    .fold(0.0, f32::max);
+
+                outranks[x][y] = concordance[x][y] >= concordance_threshold - 1e-6
+                    && discordance[x][y] <= discordance_threshold + 1e-6;
+            }
+        }
+
+        let kernel = kernel(&outranks);
+
+        ElectreResult {
+            concordance,
+            discordance,
+            outranks,
+            kernel,
+        }
+    }
+
+    /// Kernel of an acyclic outranking graph: alternatives not outranked by
+    /// each other, which together outrank every other alternative.
+    pub fn kernel(outranks: &[Vec<bool>]) -> Option<Vec<usize>> {
+        let n = outranks.len();
+        let mut remaining = vec![true; n];
+        let mut kernel = Vec::new();
+
+        while remaining.iter().any(|&remaining| remaining) {
+            let sources: Vec<usize> = (0..n)
+                .filter(|&y| remaining[y] && !(0..n).any(|x| remaining[x] && outranks[x][y]))
+                .collect();
+
+            if sources.is_empty() {
+                return None;
+            }
+
+            for &source in &sources {
+                remaining[source] = false;
+                (0..n)
+                    .filter(|&y| outranks[source][y])
+                    .for_each(|y| remaining[y] = false);
+            }
+
+            kernel.extend(sources);
+        }
+
+        kernel.sort();
+
+        Some(kernel)
+    }
+}
+
+pub mod promethee {
+    use std::fmt::Display;
+
+    use super::{advantages, Direction};
+
+    #[derive(Clone, Debug, Copy, PartialEq, Eq)]
+    pub enum PreferenceFunctionKind {
+        Usual,
+        UShape,
+        VShape,
+        Level,
+        Linear,
+        Gaussian,
+    }
+
+    impl PreferenceFunctionKind {
+        pub const ALL: [PreferenceFunctionKind; 6] = [
+            PreferenceFunctionKind::Usual,
+            PreferenceFunctionKind::UShape,
+            PreferenceFunctionKind::VShape,
+            PreferenceFunctionKind::Level,
+            PreferenceFunctionKind::Linear,
+            PreferenceFunctionKind::Gaussian,
+        ];
+    }
+
+    impl Display for PreferenceFunctionKind {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "{}",
+                match self {
+                    PreferenceFunctionKind::Usual => "Звичайна",
+                    PreferenceFunctionKind::UShape => "U-подібна",
+                    PreferenceFunctionKind::VShape => "V-подібна",
+                    PreferenceFunctionKind::Level => "Рівнева",
+                    PreferenceFunctionKind::Linear => "Лінійна",
+                    PreferenceFunctionKind::Gaussian => "Гаусова",
+                }
+            )
+        }
+    }
+
+    /// Preference function with indifference threshold `q` and preference
+    /// threshold `p` (`p` is the standard deviation for the Gaussian one).
+    #[derive(Clone, Debug, Copy, PartialEq)]
+    pub struct PreferenceFunction {
+        pub kind: PreferenceFunctionKind,
+        pub q: f32,
+        pub p: f32,
+    }
+
+    impl PreferenceFunction {
+        /// Degree of preference for an advantage `d`.
+        pub fn preference(&self, d: f32) -> f32 {
+            if d <= 0.0 {
+                return 0.0;
+            }
+
+            let (q, p) = (self.q, self.p);
+
+            match self.kind {
+                PreferenceFunctionKind::Usual => 1.0,
+                PreferenceFunctionKind::UShape => (d > q) as u8 as f32,
+                PreferenceFunctionKind::VShape if d >= p => 1.0,
+                PreferenceFunctionKind::VShape => d / p,
+                PreferenceFunctionKind::Level if d <= q => 0.0,
+                PreferenceFunctionKind::Level if d <= p => 0.5,
+                PreferenceFunctionKind::Level => 1.0,
+                PreferenceFunctionKind::Linear if d <= q => 0.0,
+                PreferenceFunctionKind::Linear if d >= p => 1.0,
+                PreferenceFunctionKind::Linear => (d - q) / (p - q),
+                PreferenceFunctionKind::Gaussian => 1.0 - (-d * d / (2.0 * p * p)).exp(),
+            }
+        }
+
+        pub fn is_valid(&self) -> bool {
+            let needs_p = !matches!(
+                self.kind,
+                PreferenceFunctionKind::Usual | PreferenceFunctionKind::UShape
+            );
+            let needs_q_below_p = matches!(
+                self.kind,
+                PreferenceFunctionKind::Level | PreferenceFunctionKind::Linear
+            );
+
+            self.q >= 0.0 && (!needs_p || self.p > 0.0) && (!needs_q_below_p || self.q < self.p)
+        }
+    }
+
+    pub struct PrometheeResult {
+        /// Aggregated preference `pi[x][y]` of `x` over `y`.
+        pub preferences: Vec<Vec<f32>>,
+        pub positive_flows: Vec<f32>,
+        pub negative_flows: Vec<f32>,
+        pub net_flows: Vec<f32>,
+    }
+
+    /// PROMETHEE II complete ranking by net outranking flows.
+    pub fn promethee(
+        a: &[Vec<f32>],
+        weights: &[f32],
+        directions: &[Direction],
+        functions: &[PreferenceFunction],
+    ) -> PrometheeResult {
+        let n = a.len();
+
+        let preferences: Vec<Vec<f32>> = (0..n)
+            .map(|x| {
+                (0..n)
+                    .map(|y| {
+                        advantages(&a[x], &a[y], directions)
+                            .iter()
+                            .zip(weights.iter().zip(functions.iter()))
+                            .map(|(&d, (weight, function))| weight * function.preference(d))
+                            .sum()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let others = (n.max(2) - 1) as f32;
+        let positive_flows: Vec<f32> = preferences
+            .iter()
+            .map(|row| row.iter().sum::<f32>() / others)
+            .collect();
+        let negative_flows: Vec<f32> = (0..n)
+            .map(|y| preferences.iter().map(|row| row[y]).sum::<f32>() / others)
+            .collect();
+        let net_flows = positive_flows
+            .iter()
+            .zip(negative_flows.iter())
+            .map(|(positive, negative)| positive - negative)
+            .collect();
+
+        PrometheeResult {
+            preferences,
+            positive_flows,
+            negative_flows,
+            net_flows,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod electre {
+        use crate::{
+            multi_criteria::Direction,
+            outranking::electre::{electre, kernel},
+        };
+
+        #[test]
+        fn test_electre() {
+            let a = vec![
+                vec![100.0, 8.0, 5.0],
+                vec![80.0, 6.0, 7.0],
+                vec![120.0, 9.0, 3.0],
+                vec![110.0, 7.0, 6.0],
+            ];
+            let weights = vec![0.5, 0.3, 0.2];
+            let directions = vec![Direction::Cost, Direction::Benefit, Direction::Cost];
+
+            let result = electre(&a, &weights, &directions, 0.6, 0.5);
+
+            assert!((result.concordance[0][1] - 0.5).abs() < 1e-5);
+            assert!((result.discordance[0][1] - 0.5).abs() < 1e-5);
+            assert_eq!(result.concordance[0][3], 1.0);
+            assert_eq!(result.discordance[0][3], 0.0);
+            assert!(result.outranks[0][3] && !result.outranks[3][0]);
+            assert_eq!(result.kernel, Some(vec![0, 1, 2]));
+        }
+
+        #[test]
+        fn test_kernel_with_cycle() {
+            let outranks = vec![
+                vec![false, true, false],
+                vec![false, false, true],
+                vec![true, false, false],
+            ];
+
+            assert_eq!(kernel(&outranks), None);
+        }
+    }
+
+    mod promethee {
+        use crate::{
+            multi_criteria::Direction,
+            outranking::promethee::{promethee, PreferenceFunction, PreferenceFunctionKind},
+        };
+
+        #[test]
+        fn test_preference_functions() {
+            let linear = PreferenceFunction {
+                kind: PreferenceFunctionKind::Linear,
+                q: 1.0,
+                p: 3.0,
+            };
+
+            assert_eq!(linear.preference(-1.0), 0.0);
+            assert_eq!(linear.preference(1.0), 0.0);
+            assert_eq!(linear.preference(2.0), 0.5);
+            assert_eq!(linear.preference(5.0), 1.0);
+            assert!(linear.is_valid());
+            assert!(!PreferenceFunction { q: 3.0, ..linear }.is_valid());
+        }
+
+        #[test]
+        fn test_promethee() {
+            let a = vec![vec![1.0, 5.0], vec![3.0, 4.0], vec![2.0, 2.0]];
+            let usual = PreferenceFunction {
+                kind: PreferenceFunctionKind::Usual,
+                q: 0.0,
+                p: 0.0,
+            };
+
+            let result = promethee(
+                &a,
+                &[0.5, 0.5],
+                &[Direction::Benefit, Direction::Benefit],
+                &[usual, usual],
+            );
+
+            assert_eq!(result.preferences[1][2], 1.0);
+            assert_eq!(result.net_flows, vec![0.0, 0.5, -0.5]);
+            assert!((result.net_flows.iter().sum::<f32>()).abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use crate::criterion::{get_indeces, get_max};
+
+/// Parameters of cumulative prospect theory (Tversky and Kahneman, 1992).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProspectTheory {
+    /// Outcomes are gains or losses relative to this point.
+    pub reference_point: f32,
+    /// Curvature of the value function for gains.
+    pub alpha: f32,
+    /// Curvature of the value function for losses.
+    pub beta: f32,
+    /// Loss aversion.
+    pub lambda: f32,
+    /// Curvature of the probability weighting function, the function stays
+    /// monotone for `γ` above about 0.28.
+    pub gamma: f32,
+}
+
+impl Default for ProspectTheory {
+    /// Estimates of the original paper.
+    fn default() -> Self {
+        ProspectTheory {
+            reference_point: 0.0,
+            alpha: 0.88,
+            beta: 0.88,
+            lambda: 2.25,
+            gamma: 0.61,
+        }
+    }
+}
+
+impl ProspectTheory {
+    /// `(x - r)^α` for gains and `-λ (r - x)^β` for losses.
+    pub fn value(&self, x: f32) -> f32 {
+        let relative = x - self.reference_point;
+
+        if relative >= 0.0 {
+            relative.powf(self.alpha)
+        } else {
+            -self.lambda * (-relative).powf(self.beta)
+        }
+    }
+
+    /// `w(p) = p^γ / (p^γ + (1 - p)^γ)^(1/γ)`.
+    pub fn weight(&self, p: f32) -> f32 {
+        if p <= 0.0 {
+            return 0.0;
+        }
+        if p >= 1.0 {
+            return 1.0;
+        }
+
+        let numerator = p.powf(self.gamma);
+
+        numerator / (numerator + (1.0 - p).powf(self.gamma)).powf(1.0 / self.gamma)
+    }
+
+    /// Decision weights of outcomes: differences of the weighted probability
+    /// of an outcome at least as good for gains, at least as bad for losses.
+    pub fn decision_weights(&self, row: &[f32], p: &[f32]) -> Vec<f32> {
+        let mut order: Vec<usize> = (0..row.len()).collect();
+        order.sort_by(|&x, &y| row[x].partial_cmp(&row[y]).unwrap());
+
+        let mut weights = vec![0.0; row.len()];
+        let is_gain = |index: usize| row[index] >= self.reference_point;
+
+        // Equal outcomes get parts of the same weight which add up correctly.
+        let mut cumulative = 0.0;
+        for &index in order.iter().filter(|&&index| !is_gain(index)) {
+            weights[index] = self.weight(cumulative + p[index]) - self.weight(cumulative);
+            cumulative += p[index];
+        }
+
+        let mut cumulative = 0.0;
+        for &index in order.iter().rev().filter(|&&index| is_gain(index)) {
+            weights[index] = self.weight(cumulative + p[index]) - self.weight(cumulative);
+            cumulative += p[index];
+        }
+
+        weights
+    }
+
+    /// Prospect value of an alternative.
+    pub fn evaluate(&self, row: &[f32], p: &[f32]) -> f32 {
+        row.iter()
+            .zip(self.decision_weights(row, p))
+            .map(|(&x, weight)| weight * self.value(x))
+            .sum()
+    }
+}
+
+pub fn prospect_values(a: &[Vec<f32>], p: &[f32], theory: &ProspectTheory) -> Vec<f32> {
+    a.iter().map(|row| theory.evaluate(row, p)).collect()
+}
+
+/// Maximizes the prospect value.
+pub fn prospect_theory(a: &[Vec<f32>], p: &[f32], theory: &ProspectTheory) -> (f32, Vec<usize>) {
+    let z = prospect_values(a, p, theory);
+    let answer = get_max(&z);
+
+    (answer, get_indeces(&z, answer))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        criterion::risk_condition::bayes,
+        prospect_theory::{prospect_theory, prospect_values, ProspectTheory},
+    };
+
+    fn linear() -> ProspectTheory {
+        ProspectTheory {
+            reference_point: 0.0,
+            alpha: 1.0,
+            beta: 1.0,
+            lambda: 1.0,
+            gamma: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_linear_matches_bayes() {
+        let a = vec![vec![100.0, -50.0, 20.0], vec![30.0, 30.0, -10.0]];
+        let p = vec![0.2, 0.5, 0.3];
+
+        let values = prospect_values(&a, &p, &linear());
+
+        for (row, value) in a.iter().zip(values) {
+            assert!((value - bayes(std::slice::from_ref(row), &p).0).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_loss_aversion() {
+        let theory = ProspectTheory {
+            lambda: 2.25,
+            ..linear()
+        };
+        let a = vec![vec![100.0, -100.0], vec![0.0, 0.0]];
+
+        assert_eq!(theory.evaluate(&a[0], &[0.5, 0.5]), -62.5);
+        assert_eq!(prospect_theory(&a, &[0.5, 0.5], &theory), (0.0, vec![1]));
+
+        // Shifting the reference point makes the sure outcome a loss.
+        let shifted = ProspectTheory {
+            reference_point: 50.0,
+            ..theory
+        };
+        assert_eq!(shifted.value(0.0), -112.5);
+    }
+
+    #[test]
+    fn test_probability_weighting() {
+        let theory = ProspectTheory::default();
+
+        assert_eq!(theory.weight(0.0), 0.0);
+        assert_eq!(theory.weight(1.0), 1.0);
+        assert!(
+            theory.weight(0.01) > 0.01,
+            "Small probabilities are overweighted."
+        );
+        assert!(
+            theory.weight(0.9) < 0.9,
+            "Large probabilities are underweighted."
+        );
+        assert!((theory.weight(0.5) - 0.4209).abs() < 1e-3);
+
+        // Weights of all-gain outcomes telescope to w(1) = 1.
+        let weights = theory.decision_weights(&[10.0, 20.0, 20.0], &[0.2, 0.3, 0.5]);
+        assert!((weights.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+    }
+}
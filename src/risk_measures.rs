@@ -0,0 +1,266 @@
+use crate::{
+    answer_block::profits_losses_radio::Choise,
+    criterion::{
+        dominance::non_dominated,
+        get_best,
+        risk_condition::{bayes_scores, dispersion_scores},
+    },
+};
+
+/// Outcomes of a row with their probabilities, sorted from the worst.
+fn sorted_distribution(row: &[f32], p: &[f32]) -> Vec<(f32, f32)> {
+    let mut distribution: Vec<(f32, f32)> = row
+        .iter()
+        .copied()
+        .zip(p.iter().copied())
+        .filter(|(_, probability)| *probability > 0.0)
+        .collect();
+    distribution.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap());
+
+    distribution
+}
+
+/// Value-at-Risk of every alternative at `confidence` level: the loss
+/// (negated payoff) which is not exceeded with probability `confidence`.
+pub fn value_at_risk(a: &[Vec<f32>], p: &[f32], confidence: f32) -> Vec<f32> {
+    let tail = 1.0 - confidence;
+
+    a.iter()
+        .map(|row| {
+            let distribution = sorted_distribution(row, p);
+            let mut cumulative = 0.0;
+
+            let quantile = distribution
+                .iter()
+                .find(|(_, probability)| {
+                    cumulative += probability;
+                    cumulative >= tail - 1e-6
+                })
+                .unwrap_or(&distribution[distribution.len() - 1])
+                .0;
+
+            -quantile
+        })
+        .collect()
+}
+
+/// Conditional Value-at-Risk (expected shortfall): expected loss over the
+/// worst `1 - confidence` of outcomes, splitting the atom at VaR.
+pub fn conditional_value_at_risk(a: &[Vec<f32>], p: &[f32], confidence: f32) -> Vec<f32> {
+    let tail = 1.0 - confidence;
+
+    a.iter()
+        .map(|row| {
+            let distribution = sorted_distribution(row, p);
+
+            if tail <= 0.0 {
+                return -distribution[0].0;
+            }
+
+            let mut remaining = tail;
+            let mut tail_sum = 0.0;
+            for (value, probability) in distribution {
+                let taken = probability.min(remaining);
+                tail_sum += value * taken;
+                remaining -= taken;
+
+                if remaining <= 0.0 {
+                    break;
+                }
+            }
+
+            -tail_sum / tail
+        })
+        .collect()
+}
+
+pub fn var_minimization(a: &[Vec<f32>], p: &[f32], confidence: f32) -> (f32, Vec<usize>) {
+    get_best(&value_at_risk(a, p, confidence), true)
+}
+
+pub fn cvar_minimization(a: &[Vec<f32>], p: &[f32], confidence: f32) -> (f32, Vec<usize>) {
+    get_best(&conditional_value_at_risk(a, p, confidence), true)
+}
+
+/// Mean–CVaR trade-off: maximizes `E - λ · CVaR`.
+pub fn mean_cvar(a: &[Vec<f32>], p: &[f32], confidence: f32, lambda: f32) -> (f32, Vec<usize>) {
+    let z: Vec<f32> = bayes_scores(a, p)
+        .into_iter()
+        .zip(conditional_value_at_risk(a, p, confidence))
+        .map(|(mean, cvar)| mean - lambda * cvar)
+        .collect();
+
+    get_best(&z, false)
+}
+
+/// Alternatives for which no other one has not lower mean and not higher
+/// standard deviation (with one of them strictly better).
+pub fn mean_variance_efficient(a: &[Vec<f32>], p: &[f32]) -> Vec<usize> {
+    let points: Vec<Vec<f32>> = bayes_scores(a, p)
+        .into_iter()
+        .zip(dispersion_scores(a, p))
+        .map(|(mean, deviation)| vec![mean, -deviation])
+        .collect();
+
+    non_dominated(&points, Choise::Profits)
+}
+
+/// `σ / |E|`, `None` for alternatives with zero mean.
+pub fn coefficients_of_variation(a: &[Vec<f32>], p: &[f32]) -> Vec<Option<f32>> {
+    bayes_scores(a, p)
+        .into_iter()
+        .zip(dispersion_scores(a, p))
+        .map(|(mean, deviation)| (mean != 0.0).then(|| deviation / mean.abs()))
+        .collect()
+}
+
+/// Minimal coefficient of variation among alternatives with non-zero mean.
+pub fn variation_minimization(a: &[Vec<f32>], p: &[f32]) -> Option<(f32, Vec<usize>)> {
+    let z = coefficients_of_variation(a, p);
+    let answer = z
+        .iter()
+        .flatten()
+        .copied()
+        .min_by(|x, y| x.partial_cmp(y).unwrap())?;
+
+    Some((
+        answer,
+        (0..z.len())
+            .filter(|&index| z[index] == Some(answer))
+            .collect(),
+    ))
+}
+
+/// Maximizes `E - k · σ`.
+pub fn mean_minus_k_sigma(a: &[Vec<f32>], p: &[f32], k: f32) -> (f32, Vec<usize>) {
+    let z: Vec<f32> = bayes_scores(a, p)
+        .into_iter()
+        .zip(dispersion_scores(a, p))
+        .map(|(mean, deviation)| mean - k * deviation)
+        .collect();
+
+    get_best(&z, false)
+}
+
+/// Lower partial moments `Σ p · max(target - x, 0)^order`. The moment of
+/// order 0 is the probability of an outcome below `target`.
+pub fn lower_partial_moments(a: &[Vec<f32>], p: &[f32], target: f32, order: f32) -> Vec<f32> {
+    a.iter()
+        .map(|row| {
+            row.iter()
+                .zip(p.iter())
+                .filter(|(&value, _)| value < target)
+                .map(|(value, probability)| (target - value).powf(order) * probability)
+                .sum()
+        })
+        .collect()
+}
+
+/// Downside semivariance below `target`, or below the mean of every
+/// alternative when `target` is `None`.
+pub fn semivariances(a: &[Vec<f32>], p: &[f32], target: Option<f32>) -> Vec<f32> {
+    a.iter()
+        .zip(bayes_scores(a, p))
+        .map(|(row, mean)| {
+            lower_partial_moments(std::slice::from_ref(row), p, target.unwrap_or(mean), 2.0)[0]
+        })
+        .collect()
+}
+
+pub fn probabilities_of_loss(a: &[Vec<f32>], p: &[f32], target: f32) -> Vec<f32> {
+    lower_partial_moments(a, p, target, 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        criterion::risk_condition::{bayes, bayes_scores, dispersion_scores},
+        risk_measures::{
+            coefficients_of_variation, conditional_value_at_risk, cvar_minimization,
+            lower_partial_moments, mean_cvar, mean_minus_k_sigma, mean_variance_efficient,
+            probabilities_of_loss, semivariances, value_at_risk, var_minimization,
+            variation_minimization,
+        },
+    };
+
+    #[test]
+    fn test_value_at_risk() {
+        let a = vec![vec![100.0, 0.0, -50.0]];
+        let p = vec![0.5, 0.3, 0.2];
+
+        assert_eq!(value_at_risk(&a, &p, 0.9), vec![50.0]);
+        assert_eq!(value_at_risk(&a, &p, 0.7), vec![0.0]);
+        assert_eq!(value_at_risk(&a, &p, 0.0), vec![-100.0]);
+    }
+
+    #[test]
+    fn test_conditional_value_at_risk() {
+        let a = vec![vec![100.0, 0.0, -50.0]];
+        let p = vec![0.5, 0.3, 0.2];
+
+        assert!((conditional_value_at_risk(&a, &p, 0.9)[0] - 50.0).abs() < 1e-3);
+        assert!((conditional_value_at_risk(&a, &p, 0.7)[0] - 100.0 / 3.0).abs() < 1e-3);
+        assert!(
+            (conditional_value_at_risk(&a, &p, 0.0)[0] + 40.0).abs() < 1e-3,
+            "CVaR at zero confidence is the negated mean."
+        );
+        assert_eq!(conditional_value_at_risk(&a, &p, 1.0), vec![50.0]);
+    }
+
+    #[test]
+    fn test_cvar_criteria() {
+        let a = vec![
+            vec![100_000.0, -50_000.0, -50_000.0],
+            vec![-50_000.0, -50_000.0, 100_000.0],
+            vec![15_000.0, 15_000.0, 0.0],
+            vec![0.0, 0.0, 0.0],
+        ];
+        let p = vec![0.5, 0.1, 0.4];
+
+        assert_eq!(var_minimization(&a, &p, 0.9).1, vec![2, 3]);
+        assert_eq!(cvar_minimization(&a, &p, 0.9), (0.0, vec![2, 3]));
+        assert_eq!(
+            mean_cvar(&a, &p, 0.9, 0.0).1,
+            bayes(&a, &p).1,
+            "Without risk aversion the mean–CVaR criterion is Bayes."
+        );
+    }
+
+    #[test]
+    fn test_mean_variance() {
+        let a = vec![
+            vec![10.0, 10.0],
+            vec![30.0, 0.0],
+            vec![20.0, 4.0],
+            vec![5.0, 5.0],
+        ];
+        let p = vec![0.5, 0.5];
+
+        assert_eq!(bayes_scores(&a, &p), vec![10.0, 15.0, 12.0, 5.0]);
+        assert_eq!(dispersion_scores(&a, &p), vec![0.0, 15.0, 8.0, 0.0]);
+        assert_eq!(mean_variance_efficient(&a, &p), vec![0, 1, 2]);
+        assert_eq!(coefficients_of_variation(&a, &p)[2], Some(8.0 / 12.0));
+        assert_eq!(variation_minimization(&a, &p), Some((0.0, vec![0, 3])));
+        assert_eq!(mean_minus_k_sigma(&a, &p, 0.0), (15.0, vec![1]));
+        assert_eq!(mean_minus_k_sigma(&a, &p, 1.0), (10.0, vec![0]));
+    }
+
+    #[test]
+    fn test_variation_minimization_zero_means() {
+        let a = vec![vec![1.0, -1.0]];
+
+        assert_eq!(variation_minimization(&a, &[0.5, 0.5]), None);
+    }
+
+    #[test]
+    fn test_downside_risk() {
+        let a = vec![vec![-10.0, 10.0, 30.0], vec![-10.0, 10.0, 10.0]];
+        let p = vec![0.25, 0.25, 0.5];
+
+        assert_eq!(semivariances(&a, &p, None), vec![162.5, 56.25]);
+        assert_eq!(semivariances(&a, &p, Some(0.0)), vec![25.0, 25.0]);
+        assert_eq!(lower_partial_moments(&a, &p, 0.0, 1.0), vec![2.5, 2.5]);
+        assert_eq!(probabilities_of_loss(&a, &p, 0.0), vec![0.25, 0.25]);
+        assert_eq!(probabilities_of_loss(&a, &p, 15.0), vec![0.5, 1.0]);
+    }
+}
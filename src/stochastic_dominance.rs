@@ -0,0 +1,132 @@
+use crate::risk_measures::lower_partial_moments;
+
+const TOLERANCE: f32 = 1e-6;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StochasticDominance {
+    /// `F_x(t) ≤ F_y(t)` for every `t`.
+    First,
+    /// `∫F_x ≤ ∫F_y` up to every `t`, but not first order.
+    Second,
+}
+
+/// Outcomes of both alternatives, the only points where the distribution
+/// functions and their integrals change slope.
+fn breakpoints(x: &[f32], y: &[f32]) -> Vec<f32> {
+    let mut points: Vec<f32> = x.iter().chain(y.iter()).copied().collect();
+    points.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    points.dedup();
+
+    points
+}
+
+/// Checks `f(x) ≤ f(y)` at every point with strict inequality somewhere.
+fn dominates_by(points: &[f32], f: impl Fn(f32) -> (f32, f32)) -> bool {
+    let values: Vec<(f32, f32)> = points.iter().map(|&t| f(t)).collect();
+
+    values.iter().all(|(x, y)| *x <= y + TOLERANCE)
+        && values.iter().any(|(x, y)| *x < y - TOLERANCE)
+}
+
+/// Checks whether payoffs `x` stochastically dominate payoffs `y` given
+/// probabilities `p` of the states.
+pub fn stochastically_dominates(x: &[f32], y: &[f32], p: &[f32]) -> Option<StochasticDominance> {
+    let points = breakpoints(x, y);
+    let cdf = |row: &[f32], t: f32| {
+        row.iter()
+            .zip(p.iter())
+            .filter(|(&value, _)| value <= t)
+            .map(|(_, probability)| probability)
+            .sum::<f32>()
+    };
+
+    if dominates_by(&points, |t| (cdf(x, t), cdf(y, t))) {
+        return Some(StochasticDominance::First);
+    }
+
+    // The integral of the distribution function up to `t` is the first
+    // lower partial moment with target `t`.
+    let integral = |row: &[f32], t: f32| lower_partial_moments(&[row.to_vec()], p, t, 1.0)[0];
+
+    dominates_by(&points, |t| (integral(x, t), integral(y, t)))
+        .then_some(StochasticDominance::Second)
+}
+
+/// Pairwise dominance, `matrix[x][y]` tells how `x` dominates `y`.
+pub fn stochastic_dominance_matrix(
+    a: &[Vec<f32>],
+    p: &[f32],
+) -> Vec<Vec<Option<StochasticDominance>>> {
+    a.iter()
+        .enumerate()
+        .map(|(x, row_x)| {
+            a.iter()
+                .enumerate()
+                .map(|(y, row_y)| {
+                    if x == y {
+                        None
+                    } else {
+                        stochastically_dominates(row_x, row_y, p)
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Alternatives not dominated by any other one in the given order. First
+/// order dominance implies second order, so `Second` checks both.
+pub fn efficient(
+    matrix: &[Vec<Option<StochasticDominance>>],
+    order: StochasticDominance,
+) -> Vec<usize> {
+    (0..matrix.len())
+        .filter(|&y| {
+            !matrix.iter().any(|row| {
+                matches!(
+                    (row[y], order),
+                    (Some(StochasticDominance::First), _)
+                        | (
+                            Some(StochasticDominance::Second),
+                            StochasticDominance::Second
+                        )
+                )
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stochastic_dominance::{
+        efficient, stochastic_dominance_matrix, stochastically_dominates, StochasticDominance,
+    };
+
+    #[test]
+    fn test_stochastically_dominates() {
+        let p = vec![0.5, 0.5];
+
+        assert_eq!(
+            stochastically_dominates(&[1.0, 3.0], &[0.0, 2.0], &p),
+            Some(StochasticDominance::First)
+        );
+        assert_eq!(
+            stochastically_dominates(&[1.0, 1.0], &[0.0, 2.0], &p),
+            Some(StochasticDominance::Second)
+        );
+        assert_eq!(stochastically_dominates(&[0.0, 2.0], &[1.0, 1.0], &p), None);
+        // Same distribution in a different order of states.
+        assert_eq!(stochastically_dominates(&[3.0, 1.0], &[1.0, 3.0], &p), None);
+    }
+
+    #[test]
+    fn test_efficient() {
+        let a = vec![vec![1.0, 3.0], vec![0.0, 2.0], vec![2.0, 2.0]];
+        let matrix = stochastic_dominance_matrix(&a, &[0.5, 0.5]);
+
+        assert_eq!(matrix[0][1], Some(StochasticDominance::First));
+        assert_eq!(matrix[2][0], Some(StochasticDominance::Second));
+        assert_eq!(efficient(&matrix, StochasticDominance::First), vec![0, 2]);
+        assert_eq!(efficient(&matrix, StochasticDominance::Second), vec![2]);
+    }
+}
@@ -24,7 +24,7 @@ impl Cell {
         }
     }
 
-    pub fn view(&self) -> Element<CellMessage> {
+    pub fn view(&self) -> Element<'_, CellMessage> {
         column![self
             .input
             .view()
@@ -1,2 +1,3 @@
 pub mod cell;
+#[allow(clippy::module_inception)]
 pub mod table;
@@ -10,6 +10,8 @@ pub struct InputTable {
     data: Vec<Vec<Cell>>,
     p: Vec<Cell>,
     pub risk_condition: bool,
+    row_label: String,
+    pub p_label: String,
 }
 
 #[derive(Clone, Debug)]
@@ -37,10 +39,17 @@ impl InputTable {
             data: matrix,
             p,
             risk_condition,
+            row_label: "x".to_string(),
+            p_label: "p".to_string(),
         }
     }
 
-    pub fn view(&self) -> Element<InputTableMessage> {
+    pub fn with_row_label(mut self, row_label: &str) -> Self {
+        self.row_label = row_label.to_string();
+        self
+    }
+
+    pub fn view(&self) -> Element<'_, InputTableMessage> {
         let mut data_vec = Vec::new();
 
         let mut heading: Vec<Element<InputTableMessage>> = Vec::new();
@@ -69,16 +78,12 @@ impl InputTable {
         for (row_index, row) in self.data.iter().enumerate() {
             let row_elements: Vec<_> = row
                 .iter()
-                .map(|cell| {
-                    cell.view()
-                        .map(move |message| InputTableMessage::CellUpdate(message))
-                        .into()
-                })
+                .map(|cell| cell.view().map(InputTableMessage::CellUpdate))
                 .collect();
 
             data_vec.push(
                 row![
-                    column![Text::new(format!("x{}", row_index + 1))]
+                    column![Text::new(format!("{}{}", self.row_label, row_index + 1))]
                         .align_items(iced::Alignment::Center)
                         .width(TEXT_CELL_WIDTH)
                         .padding(10),
@@ -101,16 +106,13 @@ impl InputTable {
             let p_table = Row::with_children(
                 self.p
                     .iter()
-                    .map(|cell| {
-                        cell.view()
-                            .map(move |message| InputTableMessage::ProbabilityCellUpdate(message))
-                    })
+                    .map(|cell| cell.view().map(InputTableMessage::ProbabilityCellUpdate))
                     .collect(),
             );
 
             content = column![
                 column![row![
-                    column![column![Text::new("p")]
+                    column![column![Text::new(&self.p_label)]
                         .align_items(iced::Alignment::Center)
                         .width(TEXT_CELL_WIDTH)
                         .padding(10),],
@@ -152,6 +154,6 @@ impl InputTable {
     }
 
     pub fn is_non_empty(&self) -> bool {
-        self.data.len() > 0 && self.data[0].len() > 0
+        !self.data.is_empty() && !self.data[0].is_empty()
     }
 }
@@ -0,0 +1,245 @@
+use std::fmt::Display;
+
+use crate::criterion::{get_indeces, get_max, risk_condition::bayes};
+
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+pub enum UtilityFunctionKind {
+    Linear,
+    Exponential,
+    Logarithmic,
+    Power,
+    PiecewiseLinear,
+}
+
+impl UtilityFunctionKind {
+    pub const ALL: [UtilityFunctionKind; 5] = [
+        UtilityFunctionKind::Linear,
+        UtilityFunctionKind::Exponential,
+        UtilityFunctionKind::Logarithmic,
+        UtilityFunctionKind::Power,
+        UtilityFunctionKind::PiecewiseLinear,
+    ];
+}
+
+impl Display for UtilityFunctionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                UtilityFunctionKind::Linear => "Лінійна",
+                UtilityFunctionKind::Exponential => "Експоненційна",
+                UtilityFunctionKind::Logarithmic => "Логарифмічна",
+                UtilityFunctionKind::Power => "Степенева",
+                UtilityFunctionKind::PiecewiseLinear => "Кусково-лінійна",
+            }
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum UtilityFunction {
+    Linear,
+    /// `R (1 - exp(-x / R))` with risk tolerance `R`, negative `R` means risk
+    /// seeking.
+    Exponential(f32),
+    /// `ln(W + x)` with initial wealth `W`.
+    Logarithmic(f32),
+    /// `sign(x) |x|^α`.
+    Power(f32),
+    /// Linear interpolation between points `(x, u)` sorted by `x`, extended
+    /// beyond them by the outer segments.
+    PiecewiseLinear(Vec<(f32, f32)>),
+}
+
+impl UtilityFunction {
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self {
+            UtilityFunction::Exponential(r) if *r == 0.0 => Err("Risk tolerance can`t be zero."),
+            UtilityFunction::Power(alpha) if *alpha <= 0.0 => Err("Power must be positive."),
+            UtilityFunction::PiecewiseLinear(points) => {
+                if points.len() < 2 {
+                    return Err("At least two points are required.");
+                }
+
+                if points
+                    .windows(2)
+                    .any(|window| window[0].0 >= window[1].0 || window[0].1 >= window[1].1)
+                {
+                    return Err("Points must strictly increase.");
+                }
+
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn utility(&self, x: f32) -> Result<f32, &'static str> {
+        match self {
+            UtilityFunction::Linear => Ok(x),
+            UtilityFunction::Exponential(r) => Ok(r * (1.0 - (-x / r).exp())),
+            UtilityFunction::Logarithmic(wealth) if wealth + x > 0.0 => Ok((wealth + x).ln()),
+            UtilityFunction::Logarithmic(_) => Err("Wealth must stay positive."),
+            UtilityFunction::Power(alpha) => Ok(x.signum() * x.abs().powf(*alpha)),
+            UtilityFunction::PiecewiseLinear(points) => Ok(interpolate(points, x)),
+        }
+    }
+
+    /// Outcome with utility `u`.
+    pub fn inverse(&self, u: f32) -> f32 {
+        match self {
+            UtilityFunction::Linear => u,
+            UtilityFunction::Exponential(r) => -r * (1.0 - u / r).ln(),
+            UtilityFunction::Logarithmic(wealth) => u.exp() - wealth,
+            UtilityFunction::Power(alpha) => u.signum() * u.abs().powf(1.0 / alpha),
+            UtilityFunction::PiecewiseLinear(points) => {
+                let swapped: Vec<(f32, f32)> = points.iter().map(|&(x, u)| (u, x)).collect();
+                interpolate(&swapped, u)
+            }
+        }
+    }
+}
+
+fn interpolate(points: &[(f32, f32)], x: f32) -> f32 {
+    let segment = points
+        .windows(2)
+        .position(|window| x <= window[1].0)
+        .unwrap_or(points.len() - 2);
+    let ((x0, y0), (x1, y1)) = (points[segment], points[segment + 1]);
+
+    y0 + (x - x0) * (y1 - y0) / (x1 - x0)
+}
+
+pub struct UtilityAnalysis {
+    pub expected_utilities: Vec<f32>,
+    pub certainty_equivalents: Vec<f32>,
+    /// Expected value minus certainty equivalent.
+    pub risk_premiums: Vec<f32>,
+    pub best: (f32, Vec<usize>),
+}
+
+/// Expected utility criterion: the utility is applied to every outcome before
+/// taking expectations.
+pub fn expected_utility(
+    a: &[Vec<f32>],
+    p: &[f32],
+    function: &UtilityFunction,
+) -> Result<UtilityAnalysis, &'static str> {
+    function.validate()?;
+
+    let utilities = a
+        .iter()
+        .map(|row| row.iter().map(|&x| function.utility(x)).collect())
+        .collect::<Result<Vec<Vec<f32>>, _>>()?;
+
+    let expected_utilities: Vec<f32> = utilities
+        .iter()
+        .map(|row| bayes(std::slice::from_ref(row), p).0)
+        .collect();
+
+    let certainty_equivalents: Vec<f32> = expected_utilities
+        .iter()
+        .map(|&u| function.inverse(u))
+        .collect();
+
+    let risk_premiums = a
+        .iter()
+        .zip(certainty_equivalents.iter())
+        .map(|(row, certainty_equivalent)| {
+            bayes(std::slice::from_ref(row), p).0 - certainty_equivalent
+        })
+        .collect();
+
+    let best_utility = get_max(&expected_utilities);
+
+    Ok(UtilityAnalysis {
+        best: (best_utility, get_indeces(&expected_utilities, best_utility)),
+        expected_utilities,
+        certainty_equivalents,
+        risk_premiums,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utility::{expected_utility, UtilityFunction};
+
+    fn generate_test_data() -> (Vec<Vec<f32>>, Vec<f32>) {
+        (vec![vec![100.0, 0.0], vec![45.0, 45.0]], vec![0.5, 0.5])
+    }
+
+    #[test]
+    fn test_linear_utility_matches_bayes() {
+        let (a, p) = generate_test_data();
+
+        let analysis = expected_utility(&a, &p, &UtilityFunction::Linear).unwrap();
+
+        assert_eq!(analysis.best, (50.0, vec![0]));
+        assert_eq!(analysis.risk_premiums, vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_risk_averse_utility() {
+        let (a, p) = generate_test_data();
+
+        for function in [
+            UtilityFunction::Exponential(50.0),
+            UtilityFunction::Logarithmic(10.0),
+            UtilityFunction::Power(0.5),
+        ] {
+            let analysis = expected_utility(&a, &p, &function).unwrap();
+
+            assert_eq!(
+                analysis.best.1,
+                vec![1],
+                "{function:?} must prefer the sure outcome."
+            );
+            assert!(analysis.risk_premiums[0] > 0.0);
+            assert!(analysis.risk_premiums[1].abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_risk_seeking_exponential_utility() {
+        let (a, p) = generate_test_data();
+
+        let analysis = expected_utility(&a, &p, &UtilityFunction::Exponential(-50.0)).unwrap();
+
+        assert_eq!(analysis.best.1, vec![0]);
+        assert!(analysis.risk_premiums[0] < 0.0);
+        assert!(analysis.risk_premiums[1].abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_exponential_certainty_equivalent() {
+        let (a, p) = generate_test_data();
+
+        let analysis = expected_utility(&a, &p, &UtilityFunction::Exponential(50.0)).unwrap();
+        let expected = -50.0 * ((1.0 + (-2.0f32).exp()) / 2.0).ln();
+
+        assert!((analysis.certainty_equivalents[0] - expected).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_piecewise_linear_utility() {
+        let function =
+            UtilityFunction::PiecewiseLinear(vec![(0.0, 0.0), (50.0, 0.8), (100.0, 1.0)]);
+
+        assert!((function.utility(25.0).unwrap() - 0.4).abs() < 1e-6);
+        assert!((function.utility(150.0).unwrap() - 1.2).abs() < 1e-6);
+        assert!((function.inverse(0.9) - 75.0).abs() < 1e-3);
+        assert!(
+            UtilityFunction::PiecewiseLinear(vec![(0.0, 1.0), (1.0, 0.0)])
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_logarithmic_utility_invalid_wealth() {
+        let (a, p) = generate_test_data();
+
+        assert!(expected_utility(&a, &p, &UtilityFunction::Logarithmic(0.0)).is_err());
+    }
+}
@@ -1,4 +1,6 @@
-pub fn parse_data(a: &Vec<Vec<String>>) -> Result<Vec<Vec<f32>>, &str> {
+use crate::{fuzzy::FuzzyNumber, interval::Interval, monte_carlo::Distribution};
+
+pub fn parse_data(a: &[Vec<String>]) -> Result<Vec<Vec<f32>>, &str> {
     let mut parsed_data = Vec::with_capacity(a.len());
 
     for row in a {
@@ -14,15 +16,15 @@ pub fn parse_data(a: &Vec<Vec<String>>) -> Result<Vec<Vec<f32>>, &str> {
         parsed_data.push(parsed_row);
     }
 
-    return Ok(parsed_data);
+    Ok(parsed_data)
 }
 
-pub fn parse_p(p: &Vec<String>) -> Result<Vec<f32>, &str> {
+pub fn parse_p(p: &[String]) -> Result<Vec<f32>, &str> {
     let mut parsed_p = Vec::with_capacity(p.len());
 
     for cell in p {
         if let Ok(parsed_cell_value) = cell.parse::<f32>() {
-            if 0.0 <= parsed_cell_value && parsed_cell_value <= 1.0 {
+            if (0.0..=1.0).contains(&parsed_cell_value) {
                 parsed_p.push(parsed_cell_value);
             } else {
                 return Err("P is invalid.");
@@ -32,16 +34,101 @@ pub fn parse_p(p: &Vec<String>) -> Result<Vec<f32>, &str> {
         }
     }
 
-    if parsed_p.iter().sum::<f32>() != 1.0 {
+    // Probabilities pasted from computations (e.g. AHP) are rounded.
+    if (parsed_p.iter().sum::<f32>() - 1.0).abs() > 1e-4 {
         return Err("Sum of p doesn`t equal 1.");
     }
 
-    return Ok(parsed_p);
+    Ok(parsed_p)
+}
+
+/// Parses non-negative attribute weights and normalizes them to sum up to 1.
+pub fn parse_weights(w: &[String]) -> Result<Vec<f32>, &str> {
+    let mut parsed_w = Vec::with_capacity(w.len());
+
+    for cell in w {
+        match cell.parse::<f32>() {
+            Ok(parsed_cell_value) if parsed_cell_value >= 0.0 => parsed_w.push(parsed_cell_value),
+            _ => return Err("Weights are invalid."),
+        }
+    }
+
+    let sum = parsed_w.iter().sum::<f32>();
+    if sum <= 0.0 {
+        return Err("Sum of weights must be positive.");
+    }
+
+    Ok(parsed_w.iter().map(|weight| weight / sum).collect())
+}
+
+/// Parses numbers separated by `;`.
+pub fn parse_list(list: &str) -> Result<Vec<f32>, &str> {
+    list.split(';')
+        .map(|value| value.trim().parse::<f32>().map_err(|_| "List is invalid."))
+        .collect()
+}
+
+/// Parses cells holding numbers or distributions, see [`Distribution::parse`].
+pub fn parse_distributions(a: &[Vec<String>]) -> Result<Vec<Vec<Distribution>>, &str> {
+    a.iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| Distribution::parse(cell).map_err(|_| "Matrix is invalid."))
+                .collect()
+        })
+        .collect()
+}
+
+/// Parses cells holding numbers or intervals `[a; b]`.
+pub fn parse_intervals(a: &[Vec<String>]) -> Result<Vec<Vec<Interval>>, &str> {
+    a.iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| Interval::parse(cell).map_err(|_| "Matrix is invalid."))
+                .collect()
+        })
+        .collect()
+}
+
+/// Parses cells holding numbers or fuzzy numbers `(a; b; c)`, `(a; b; c; d)`.
+pub fn parse_fuzzy_numbers(a: &[Vec<String>]) -> Result<Vec<Vec<FuzzyNumber>>, &str> {
+    a.iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| FuzzyNumber::parse(cell).map_err(|_| "Matrix is invalid."))
+                .collect()
+        })
+        .collect()
+}
+
+/// Parses points written as `x1:y1; x2:y2; ...`.
+pub fn parse_points(points: &str) -> Result<Vec<(f32, f32)>, &str> {
+    points
+        .split(';')
+        .filter(|point| !point.trim().is_empty())
+        .map(|point| {
+            let (x, y) = point.split_once(':').ok_or("Points are invalid.")?;
+
+            match (x.trim().parse::<f32>(), y.trim().parse::<f32>()) {
+                (Ok(x), Ok(y)) => Ok((x, y)),
+                _ => Err("Points are invalid."),
+            }
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::parse_data;
+    use crate::{
+        criterion::Payoff,
+        fuzzy::FuzzyNumber,
+        interval::Interval,
+        monte_carlo::Distribution,
+        utils::{
+            parse_data, parse_distributions, parse_fuzzy_numbers, parse_intervals, parse_list,
+            parse_p, parse_points, parse_weights,
+        },
+    };
 
     #[test]
     fn test_parse_data() {
@@ -96,4 +183,95 @@ mod tests {
             "First value in matrix is empty space."
         );
     }
+
+    #[test]
+    fn test_parse_p() {
+        let to_strings = |p: &[&str]| p.iter().map(|cell| cell.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            parse_p(&to_strings(&["0.5", "0.25", "0.25"])),
+            Ok(vec![0.5, 0.25, 0.25])
+        );
+        assert!(
+            parse_p(&to_strings(&["0.3333", "0.3333", "0.3334"])).is_ok(),
+            "Rounded probabilities must be accepted."
+        );
+        assert!(parse_p(&to_strings(&["0.33333", "0.33333", "0.33333"])).is_ok());
+        assert_eq!(
+            parse_p(&to_strings(&["0.333", "0.333", "0.333"])),
+            Err("Sum of p doesn`t equal 1.")
+        );
+        assert_eq!(
+            parse_p(&to_strings(&["0.5", "0.6"])),
+            Err("Sum of p doesn`t equal 1.")
+        );
+        assert_eq!(parse_p(&to_strings(&["1.5", "-0.5"])), Err("P is invalid."));
+    }
+
+    #[test]
+    fn test_parse_weights() {
+        let w = vec!["2".to_string(), "1".to_string(), "1".to_string()];
+
+        assert_eq!(parse_weights(&w), Ok(vec![0.5, 0.25, 0.25]));
+        assert!(parse_weights(&["-1".to_string(), "2".to_string()]).is_err());
+        assert!(parse_weights(&["0".to_string(), "0".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_parse_points() {
+        assert_eq!(
+            parse_points("0:0; 50 : 0.8;100:1;"),
+            Ok(vec![(0.0, 0.0), (50.0, 0.8), (100.0, 1.0)])
+        );
+        assert!(parse_points("0:0; 50").is_err());
+        assert!(parse_points("a:1").is_err());
+    }
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(parse_list(" 1; 2.5 ;-3"), Ok(vec![1.0, 2.5, -3.0]));
+        assert!(parse_list("1;").is_err());
+    }
+
+    #[test]
+    fn test_parse_distributions() {
+        let a = vec![vec!["1".to_string(), "N(2; 0.5)".to_string()]];
+
+        assert_eq!(
+            parse_distributions(&a),
+            Ok(vec![vec![
+                Distribution::Constant(1.0),
+                Distribution::Normal(2.0, 0.5)
+            ]])
+        );
+        assert!(parse_distributions(&[vec!["N(2; -1)".to_string()]]).is_err());
+    }
+
+    #[test]
+    fn test_parse_intervals() {
+        let a = vec![vec!["[80; 120]".to_string(), "100".to_string()]];
+
+        assert_eq!(
+            parse_intervals(&a),
+            Ok(vec![vec![
+                Interval::new(80.0, 120.0),
+                Interval::point(100.0)
+            ]])
+        );
+        assert!(parse_intervals(&[vec!["U(1; 2)".to_string()]]).is_err());
+    }
+
+    #[test]
+    fn test_parse_fuzzy_numbers() {
+        let a = vec![vec!["(1; 2; 3)".to_string(), "4".to_string()]];
+
+        assert_eq!(
+            parse_fuzzy_numbers(&a),
+            Ok(vec![vec![
+                FuzzyNumber::new(1.0, 2.0, 2.0, 3.0),
+                FuzzyNumber::crisp(4.0)
+            ]])
+        );
+        assert!(parse_fuzzy_numbers(&[vec!["[1; 2]".to_string()]]).is_err());
+    }
 }
@@ -24,10 +24,9 @@ impl ValueInput {
         }
     }
 
-    pub fn view(&self) -> Element<ValueInputMessage> {
-        row![text_input(&self.placeholder, &self.value)
-            .on_input(|value| ValueInputMessage::ValueChanged(value))]
-        .padding(10)
-        .into()
+    pub fn view(&self) -> Element<'_, ValueInputMessage> {
+        row![text_input(&self.placeholder, &self.value).on_input(ValueInputMessage::ValueChanged)]
+            .padding(10)
+            .into()
     }
 }